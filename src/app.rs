@@ -1,13 +1,19 @@
 use std::cell::{Cell, RefCell};
-use std::collections::HashMap;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 use std::time::Instant;
 
 use ratatui::text::Line;
 
 use crate::config::{Config, StatusFilter};
+use crate::flow::{self, FlowStats};
 use crate::github::GithubPR;
-use crate::jira::{self, IssueDetail, JiraNotification, JiraUser, MentionInsert, Transition};
-use crate::notes;
+use crate::jira::{
+    self, IssueDetail, JiraNotification, JiraUser, MentionInsert, Transition, TransitionFieldKind,
+};
+use crate::notes::{self, ManualEntry, RecentIssue};
+use crate::query::{self, Field, Op, Query};
 
 pub struct MentionState {
     pub trigger_pos: usize,
@@ -59,106 +65,355 @@ pub const HIGHLIGHT_OPTIONS: [HighlightColor; 2] = [
     HighlightColor::Green,
 ];
 
-#[derive(PartialEq, Clone, Copy)]
+#[derive(PartialEq, Clone)]
 pub enum SortCriteria {
     Default,
     Board,
     Priority,
     Muted,
     Highlight,
+    Updated,
+    Created,
+    Due,
+    StatusCategory,
+    Assignee,
+    IssueType,
+    /// Own order, moved with `J`/`K`
+    Manual,
+    /// Configured custom field, by friendly name
+    Custom(String),
 }
 
 impl SortCriteria {
-    pub const ALL: [SortCriteria; 5] = [
+    pub const ALL: [SortCriteria; 12] = [
         SortCriteria::Default,
+        SortCriteria::Manual,
         SortCriteria::Board,
         SortCriteria::Priority,
         SortCriteria::Muted,
         SortCriteria::Highlight,
+        SortCriteria::Updated,
+        SortCriteria::Created,
+        SortCriteria::Due,
+        SortCriteria::StatusCategory,
+        SortCriteria::Assignee,
+        SortCriteria::IssueType,
     ];
 
-    pub fn label(&self) -> &'static str {
+    pub fn label(&self) -> &str {
         match self {
             SortCriteria::Default => "Default (Jira order)",
             SortCriteria::Board => "Board (project prefix)",
             SortCriteria::Priority => "Priority",
-            SortCriteria::Muted => "Muted (sink to bottom)",
-            SortCriteria::Highlight => "Highlight (float to top)",
+            SortCriteria::Muted => "Muted",
+            SortCriteria::Highlight => "Highlight",
+            SortCriteria::Updated => "Updated",
+            SortCriteria::Created => "Created",
+            SortCriteria::Due => "Due date",
+            SortCriteria::StatusCategory => "Status category",
+            SortCriteria::Assignee => "Assignee",
+            SortCriteria::IssueType => "Issue type",
+            SortCriteria::Manual => "Manual (J/K to move)",
+            SortCriteria::Custom(name) => name,
         }
     }
 
-    pub fn as_str(&self) -> &'static str {
+    /// Config name; custom fields go by their friendly name
+    pub fn as_str(&self) -> &str {
         match self {
             SortCriteria::Default => "default",
             SortCriteria::Board => "board",
             SortCriteria::Priority => "priority",
             SortCriteria::Muted => "muted",
             SortCriteria::Highlight => "highlight",
+            SortCriteria::Updated => "updated",
+            SortCriteria::Created => "created",
+            SortCriteria::Due => "due",
+            SortCriteria::StatusCategory => "status_category",
+            SortCriteria::Assignee => "assignee",
+            SortCriteria::IssueType => "issue_type",
+            SortCriteria::Manual => "manual",
+            SortCriteria::Custom(name) => name,
+        }
+    }
+
+    /// Built-in sort key by config name.
+    pub fn from_str(s: &str) -> Option<Self> {
+        SortCriteria::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+
+    /// Direction a newly added key starts with
+    fn default_descending(&self) -> bool {
+        matches!(
+            self,
+            SortCriteria::Priority | SortCriteria::Updated | SortCriteria::Created
+        )
+    }
+
+    pub fn direction_label(&self, descending: bool) -> &'static str {
+        match (self, descending) {
+            (SortCriteria::Default, false) => "as fetched",
+            (SortCriteria::Default, true) => "reversed",
+            (SortCriteria::Manual, false) => "my order",
+            (SortCriteria::Manual, true) => "reversed",
+            (SortCriteria::Priority, false) => "lowest first",
+            (SortCriteria::Priority, true) => "highest first",
+            (SortCriteria::Muted, false) => "muted last",
+            (SortCriteria::Muted, true) => "muted first",
+            (SortCriteria::Highlight, false) => "highlighted first",
+            (SortCriteria::Highlight, true) => "highlighted last",
+            (SortCriteria::Updated | SortCriteria::Created, false) => "oldest first",
+            (SortCriteria::Updated | SortCriteria::Created, true) => "newest first",
+            (SortCriteria::Due, false) => "soonest first",
+            (SortCriteria::Due, true) => "latest first",
+            (SortCriteria::StatusCategory, false) => "to do first",
+            (SortCriteria::StatusCategory, true) => "done first",
+            (_, false) => "A → Z",
+            (_, true) => "Z → A",
+        }
+    }
+}
+
+/// One level of a multi-key sort.
+#[derive(PartialEq, Clone)]
+pub struct SortKey {
+    pub criteria: SortCriteria,
+    pub descending: bool,
+}
+
+impl SortKey {
+    fn new(criteria: SortCriteria) -> Self {
+        let descending = criteria.default_descending();
+        SortKey { criteria, descending }
+    }
+}
+
+/// Sort keys from `Config.sort_order`: comma separated names with an optional
+/// `:asc`/`:desc`, e.g. `highlight:asc,priority:desc,updated:desc`.
+/// Whether a name is taken by a built-in sort key, column or search field.
+/// Built-ins always win, so custom fields may not use these names.
+pub fn is_builtin_name(name: &str) -> bool {
+    let lower = name.trim().to_lowercase();
+    let config_name = lower.replace(' ', "_");
+    SortCriteria::ALL
+        .iter()
+        .any(|c| c.as_str() == config_name || c.label().eq_ignore_ascii_case(&lower))
+        || Column::ALL
+            .iter()
+            .any(|c| c.as_str() == config_name || c.label().eq_ignore_ascii_case(&lower))
+        || query::builtin_field(&config_name).is_some()
+}
+
+/// Sort keys from config, built-in names before custom fields.
+fn parse_sort_order(order: &str, config: &Config) -> Vec<SortKey> {
+    let keys: Vec<SortKey> = order
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .map(|entry| {
+            let (name, descending) = match entry.rsplit_once(':') {
+                Some((name, "asc")) => (name, Some(false)),
+                Some((name, "desc")) => (name, Some(true)),
+                _ => (entry, None),
+            };
+            let criteria = SortCriteria::from_str(name)
+                .or_else(|| {
+                    let custom = config.custom_field(name)?;
+                    Some(SortCriteria::Custom(custom.name.clone()))
+                })
+                .unwrap_or(SortCriteria::Default);
+            let mut key = SortKey::new(criteria);
+            key.descending = descending.unwrap_or(key.descending);
+            key
+        })
+        .take(MAX_SORT_KEYS)
+        .collect();
+    if keys.is_empty() {
+        vec![SortKey::new(SortCriteria::Default)]
+    } else {
+        keys
+    }
+}
+
+const MAX_SORT_KEYS: usize = 3;
+
+/// Seconds an issue may go unfetched before the manual order forgets it
+const MANUAL_ORDER_EXPIRY: i64 = 90 * 24 * 60 * 60;
+/// Seconds between updates of an issue's last-seen time, to avoid a save per refresh
+const MANUAL_SEEN_RESOLUTION: i64 = 24 * 60 * 60;
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum GroupBy {
+    None,
+    Project,
+    Status,
+    StatusCategory,
+    Priority,
+    IssueType,
+    Epic,
+    Highlight,
+}
+
+impl GroupBy {
+    pub const ALL: [GroupBy; 8] = [
+        GroupBy::None,
+        GroupBy::Project,
+        GroupBy::Status,
+        GroupBy::StatusCategory,
+        GroupBy::Priority,
+        GroupBy::IssueType,
+        GroupBy::Epic,
+        GroupBy::Highlight,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            GroupBy::None => "None",
+            GroupBy::Project => "Project",
+            GroupBy::Status => "Status",
+            GroupBy::StatusCategory => "Status category",
+            GroupBy::Priority => "Priority",
+            GroupBy::IssueType => "Issue type",
+            GroupBy::Epic => "Epic",
+            GroupBy::Highlight => "Highlight colour",
+        }
+    }
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            GroupBy::None => "none",
+            GroupBy::Project => "project",
+            GroupBy::Status => "status",
+            GroupBy::StatusCategory => "status_category",
+            GroupBy::Priority => "priority",
+            GroupBy::IssueType => "issue_type",
+            GroupBy::Epic => "epic",
+            GroupBy::Highlight => "highlight",
         }
     }
 
     pub fn from_str(s: &str) -> Self {
         match s {
-            "board" => SortCriteria::Board,
-            "priority" => SortCriteria::Priority,
-            "muted" => SortCriteria::Muted,
-            "highlight" => SortCriteria::Highlight,
-            _ => SortCriteria::Default,
+            "project" => GroupBy::Project,
+            "status" => GroupBy::Status,
+            "status_category" => GroupBy::StatusCategory,
+            "priority" => GroupBy::Priority,
+            "issue_type" => GroupBy::IssueType,
+            "epic" => GroupBy::Epic,
+            "highlight" => GroupBy::Highlight,
+            _ => GroupBy::None,
         }
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+#[derive(Clone, PartialEq)]
 pub enum Column {
     Assignee,
     Reporter,
     Priority,
+    IssueType,
     Status,
     Resolution,
     Created,
+    Updated,
+    Due,
+    StoryPoints,
+    Sprint,
+    Labels,
+    Components,
+    /// Configured custom field, by friendly name
+    Custom(String),
 }
 
 impl Column {
-    pub const ALL: [Column; 6] = [
+    pub const ALL: [Column; 13] = [
         Column::Assignee,
         Column::Reporter,
         Column::Priority,
+        Column::IssueType,
         Column::Status,
         Column::Resolution,
         Column::Created,
+        Column::Updated,
+        Column::Due,
+        Column::StoryPoints,
+        Column::Sprint,
+        Column::Labels,
+        Column::Components,
     ];
 
-    pub fn label(&self) -> &'static str {
+    /// Shown when the config has no column list of its own
+    const DEFAULT: [Column; 4] = [
+        Column::Assignee,
+        Column::Reporter,
+        Column::Status,
+        Column::Sprint,
+    ];
+
+    pub fn label(&self) -> &str {
         match self {
             Column::Assignee => "Assignee",
             Column::Reporter => "Reporter",
             Column::Priority => "Priority",
+            Column::IssueType => "Type",
             Column::Status => "Status",
             Column::Resolution => "Resolution",
             Column::Created => "Created",
+            Column::Updated => "Updated",
+            Column::Due => "Due",
+            Column::StoryPoints => "Points",
+            Column::Sprint => "Sprint",
+            Column::Labels => "Labels",
+            Column::Components => "Components",
+            Column::Custom(name) => name,
         }
     }
 
-    pub fn as_str(&self) -> &'static str {
+    /// Config name; custom fields go by their friendly name
+    pub fn as_str(&self) -> &str {
         match self {
             Column::Assignee => "assignee",
             Column::Reporter => "reporter",
             Column::Priority => "priority",
+            Column::IssueType => "issue_type",
             Column::Status => "status",
             Column::Resolution => "resolution",
             Column::Created => "created",
+            Column::Updated => "updated",
+            Column::Due => "due",
+            Column::StoryPoints => "story_points",
+            Column::Sprint => "sprint",
+            Column::Labels => "labels",
+            Column::Components => "components",
+            Column::Custom(name) => name,
+        }
+    }
+
+    /// Built-in column by config name.
+    pub fn from_str(s: &str) -> Option<Column> {
+        Column::ALL.into_iter().find(|c| c.as_str() == s)
+    }
+
+    fn default_width(&self) -> u16 {
+        match self {
+            Column::Assignee | Column::Status | Column::Labels | Column::Components => 16,
+            Column::Reporter | Column::IssueType | Column::Created | Column::Due => 10,
+            Column::Priority | Column::Updated => 8,
+            Column::Resolution => 12,
+            Column::StoryPoints => 6,
+            Column::Sprint | Column::Custom(_) => 14,
         }
     }
 }
 
 fn priority_rank(p: &str) -> u8 {
-    match p {
-        "Blocker" => 6,
-        "Critical" | "Highest" => 5,
-        "Major" | "High" => 4,
-        "Normal" | "Medium" => 3,
-        "Minor" | "Low" => 2,
-        "Trivial" | "Lowest" => 1,
+    match p.to_lowercase().as_str() {
+        "blocker" => 6,
+        "critical" | "highest" => 5,
+        "major" | "high" => 4,
+        "normal" | "medium" => 3,
+        "minor" | "low" => 2,
+        "trivial" | "lowest" => 1,
         _ => 0,
     }
 }
@@ -178,30 +433,175 @@ pub enum Mode {
     DetailConfirmDelete,
     DetailTransition,
     DetailConfirmTransition,
+    TransitionPicker,
+    ConfirmTransition,
+    AssignPicker,
+    AddingLabel,
     DetailEditingSummary,
-    DetailPRList,
+    DetailRelated,
+    DetailWatchers,
+    DetailAddingWatcher,
+    DetailSearching,
     HighlightPicker,
     SortPicker,
+    GroupPicker,
+    SprintPicker,
+    Reports,
+    Stats,
     ColumnPicker,
     ConfirmQuit,
     Notifications,
+    QuickOpen,
+}
+
+#[derive(PartialEq, Clone, Copy)]
+pub enum DetailTab {
+    Description,
+    Comments,
+    History,
+    Links,
+    Subtasks,
+    PRs,
+}
+
+impl DetailTab {
+    pub const ALL: [DetailTab; 6] = [
+        DetailTab::Description,
+        DetailTab::Comments,
+        DetailTab::History,
+        DetailTab::Links,
+        DetailTab::Subtasks,
+        DetailTab::PRs,
+    ];
+
+    pub fn label(&self) -> &'static str {
+        match self {
+            DetailTab::Description => "Description",
+            DetailTab::Comments => "Comments",
+            DetailTab::History => "History",
+            DetailTab::Links => "Links",
+            DetailTab::Subtasks => "Subtasks",
+            DetailTab::PRs => "PRs",
+        }
+    }
+
+    pub fn index(&self) -> usize {
+        DetailTab::ALL.iter().position(|t| t == self).unwrap_or(0)
+    }
+
+    /// Tabs whose content is a list of selectable items.
+    pub fn is_list(&self) -> bool {
+        matches!(self, DetailTab::Links | DetailTab::Subtasks | DetailTab::PRs)
+    }
 }
 
 #[derive(Clone)]
 pub struct DisplayRow {
-    pub issue: jira::JiraIssue,
+    /// Shared with `all_rows`, so filtering doesn't copy issues
+    pub issue: Rc<jira::JiraIssue>,
     pub depth: u8,
     pub is_context_parent: bool,
+    /// Shown in the pinned section at the top of the table
+    pub is_pinned: bool,
     pub original_index: usize,
+    /// Matched only by the Jira text search, not the local search
+    pub from_text_search: bool,
+}
+
+/// Jira `text ~` results for the search text they were run with.
+struct DeepSearch {
+    text: String,
+    issues: Vec<Rc<jira::JiraIssue>>,
+}
+
+/// A board view column: the statuses it holds and its cards as `rows` indices.
+pub struct BoardLane {
+    pub name: String,
+    pub status_ids: Vec<String>,
+    pub cards: Vec<usize>,
+}
+
+/// Input for the required fields of a transition screen, plus an optional comment.
+/// `focused` indexes the fields; `fields.len()` is the comment input.
+pub struct TransitionForm {
+    pub options: Vec<Option<usize>>,
+    pub texts: Vec<String>,
+    pub comment: String,
+    pub focused: usize,
+}
+
+impl TransitionForm {
+    fn new(transition: &Transition) -> Self {
+        let n = transition.fields.len();
+        Self {
+            // Preselect when there's only one choice
+            options: transition
+                .fields
+                .iter()
+                .map(|f| (f.allowed_values.len() == 1).then_some(0))
+                .collect(),
+            texts: vec![String::new(); n],
+            comment: String::new(),
+            focused: 0,
+        }
+    }
+
+    pub fn focused_text(&self) -> Option<&String> {
+        if self.focused == self.texts.len() {
+            Some(&self.comment)
+        } else {
+            self.texts.get(self.focused)
+        }
+    }
+
+    pub fn focused_text_mut(&mut self) -> Option<&mut String> {
+        if self.focused == self.texts.len() {
+            Some(&mut self.comment)
+        } else {
+            self.texts.get_mut(self.focused)
+        }
+    }
 }
 
 pub struct DetailRenderCache {
     pub version: u64,
-    pub selected_comment: Option<usize>,
+    pub tab: DetailTab,
+    /// Selected comment, or selected item on list tabs
+    pub selected: Option<usize>,
     pub render_width: u16,
     pub lines: Vec<Line<'static>>,
     pub link_map: Vec<Option<String>>,
-    pub comment_offsets: Vec<usize>,
+    /// First line of each comment / list item
+    pub item_offsets: Vec<usize>,
+}
+
+/// Case-insensitive occurrences of `needle` in rendered lines, as
+/// (line index, start char, char length).
+pub fn find_line_matches(lines: &[Line], needle: &str) -> Vec<(usize, usize, usize)> {
+    let fold = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let needle: Vec<char> = needle.chars().map(fold).collect();
+    if needle.is_empty() {
+        return Vec::new();
+    }
+    let mut matches = Vec::new();
+    for (i, line) in lines.iter().enumerate() {
+        let text: Vec<char> = line
+            .spans
+            .iter()
+            .flat_map(|s| s.content.chars())
+            .map(fold)
+            .collect();
+        let mut start = 0;
+        while start + needle.len() <= text.len() {
+            if text[start..start + needle.len()] == needle[..] {
+                matches.push((i, start, needle.len()));
+                start += needle.len();
+            } else {
+                start += 1;
+            }
+        }
+    }
+    matches
 }
 
 pub struct App {
@@ -230,6 +630,11 @@ pub struct App {
     pub detail_lines: Cell<usize>,
     // Comment interaction state
     pub detail_comment_selected: Option<usize>,
+    pub detail_tab: DetailTab,
+    /// Scroll position of each tab, indexed like `DetailTab::ALL`
+    pub detail_tab_scroll: [u16; DetailTab::ALL.len()],
+    /// Selected item on the Links / Subtasks / PRs tabs
+    pub detail_item_selected: usize,
     pub comment_input: String,
     pub editing_comment_id: Option<String>,
     // Text input cursor
@@ -239,13 +644,30 @@ pub struct App {
     pub detail_content_y: Cell<u16>,
     pub detail_content_height: Cell<u16>,
     // Comment line offsets (set during rendering, used for auto-scroll)
-    pub detail_comment_offsets: RefCell<Vec<usize>>,
+    pub detail_item_offsets: RefCell<Vec<usize>>,
     // Detail render cache (avoids rebuilding markdown on every frame)
     pub detail_content_version: Cell<u64>,
     pub detail_render_cache: RefCell<Option<DetailRenderCache>>,
+    // In-detail text search (`/`), matched against the render cache
+    pub detail_search_input: String,
+    pub detail_match_selected: usize,
+    // In-detail navigation history (previous detail + its tab and scroll position)
+    pub detail_back: Vec<(IssueDetail, DetailTab, u16)>,
+    pub detail_forward: Vec<(IssueDetail, DetailTab, u16)>,
+    pub related_selected: usize,
+    // Watcher list state
+    pub watchers: Vec<JiraUser>,
+    pub watchers_selected: usize,
+    pub watcher_input: String,
+    pub watcher_candidates: Vec<JiraUser>,
+    pub watcher_candidate_selected: usize,
+    pub last_watcher_query: String,
     // Transition picker state
     pub transitions: Vec<Transition>,
     pub transition_selected: usize,
+    pub transition_form: Option<TransitionForm>,
+    /// Issues the picked transition applies to, with each one's own transitions
+    pub transition_targets: Vec<(String, Vec<Transition>)>,
     // Current user identity
     pub current_account_id: String,
     // Legend toggle
@@ -263,17 +685,61 @@ pub struct App {
     pub detail_status_set_at: Instant,
     // Sort picker state
     pub sort_selected: usize,
-    pub sort_criteria: SortCriteria,
+    pub group_by: GroupBy,
+    pub group_selected: usize,
+    /// Primary key first; never empty
+    pub sort_keys: Vec<SortKey>,
     // Column picker state
     pub column_picker_selected: usize,
     // PR list state
     pub pr_list: Vec<GithubPR>,
-    pub pr_list_selected: usize,
+    /// Issue `pr_list` was fetched for
+    pub pr_list_key: Option<String>,
     // Notifications state
     pub notifications: Vec<JiraNotification>,
     pub notifications_selected: usize,
     pub dismissed_notifications: std::collections::HashSet<String>,
     pub notifications_detail_open: bool,
+    // Quick-open (jump to issue by key) state
+    pub quick_open_input: String,
+    pub quick_open_selected: Option<usize>,
+    // Recently viewed and pinned issues
+    pub recent: Vec<RecentIssue>,
+    pub pinned_keys: Vec<String>,
+    /// Tree parents whose descendants are hidden in the table
+    pub collapsed_keys: HashSet<String>,
+    /// Roll-up of each collapsed parent, refreshed by `apply_search_filter`
+    collapsed_summaries: HashMap<String, (usize, String)>,
+    /// Issues in the user's own order, for `SortCriteria::Manual`
+    manual_order: Vec<ManualEntry>,
+    deep_search: Option<DeepSearch>,
+    /// Board view: rows laid out in status columns instead of the table
+    pub board_view: bool,
+    board_columns: Vec<jira::BoardColumn>,
+    // Active and next sprint of the configured board
+    pub sprint: Option<jira::Sprint>,
+    pub next_sprint: Option<jira::Sprint>,
+    pub sprint_keys: HashSet<String>,
+    pub sprint_done: usize,
+    pub sprint_only: bool,
+    pub sprint_picker_selected: usize,
+    // Reports screen
+    pub burndown: Option<jira::Burndown>,
+    pub velocity: Vec<jira::SprintVelocity>,
+    /// Status id → category key, for cycle times and velocity
+    pub status_categories: HashMap<String, String>,
+    pub flow_stats: Option<FlowStats>,
+    stats_period: usize,
+    // Multi-select: marked keys, plus the anchor row and prior marks of a `V` range
+    pub marked: HashSet<String>,
+    pub visual_anchor: Option<usize>,
+    visual_base: HashSet<String>,
+    // Bulk assign / label input
+    pub assign_input: String,
+    pub assign_candidates: Vec<JiraUser>,
+    pub assign_selected: usize,
+    last_assign_query: String,
+    pub label_input: String,
 }
 
 impl App {
@@ -282,11 +748,12 @@ impl App {
         let long_notes = notes::load_long_notes();
         let highlighted_keys = notes::load_highlights();
         let muted_keys = notes::load_muted();
-        let sort_criteria = config
-            .sort_order
+        let sort_keys = parse_sort_order(config.sort_order.as_deref().unwrap_or(""), &config);
+        let group_by = config
+            .group_by
             .as_deref()
-            .map(SortCriteria::from_str)
-            .unwrap_or(SortCriteria::Default);
+            .map(GroupBy::from_str)
+            .unwrap_or(GroupBy::None);
         App {
             rows: Vec::new(),
             all_rows: Vec::new(),
@@ -310,17 +777,33 @@ impl App {
             detail_scroll: 0,
             detail_lines: Cell::new(0),
             detail_comment_selected: None,
+            detail_tab: DetailTab::Description,
+            detail_tab_scroll: [0; DetailTab::ALL.len()],
+            detail_item_selected: 0,
             comment_input: String::new(),
             editing_comment_id: None,
             cursor_pos: 0,
             detail_link_map: RefCell::new(Vec::new()),
             detail_content_y: Cell::new(0),
             detail_content_height: Cell::new(0),
-            detail_comment_offsets: RefCell::new(Vec::new()),
+            detail_item_offsets: RefCell::new(Vec::new()),
             detail_content_version: Cell::new(0),
             detail_render_cache: RefCell::new(None),
+            detail_search_input: String::new(),
+            detail_match_selected: 0,
+            detail_back: Vec::new(),
+            detail_forward: Vec::new(),
+            related_selected: 0,
+            watchers: Vec::new(),
+            watchers_selected: 0,
+            watcher_input: String::new(),
+            watcher_candidates: Vec::new(),
+            watcher_candidate_selected: 0,
+            last_watcher_query: String::new(),
             transitions: Vec::new(),
             transition_selected: 0,
+            transition_form: None,
+            transition_targets: Vec::new(),
             current_account_id: String::new(),
             show_legend: false,
             mention: None,
@@ -331,14 +814,45 @@ impl App {
             detail_status_msg: String::new(),
             detail_status_set_at: Instant::now(),
             sort_selected: 0,
-            sort_criteria,
+            group_by,
+            group_selected: 0,
+            sort_keys,
             column_picker_selected: 0,
             pr_list: Vec::new(),
-            pr_list_selected: 0,
+            pr_list_key: None,
             notifications: Vec::new(),
             notifications_selected: 0,
             dismissed_notifications: notes::load_dismissed_notifications(),
             notifications_detail_open: false,
+            quick_open_input: String::new(),
+            quick_open_selected: None,
+            recent: notes::load_recent(),
+            pinned_keys: notes::load_pinned(),
+            collapsed_keys: notes::load_collapsed(),
+            collapsed_summaries: HashMap::new(),
+            manual_order: notes::load_manual_order(),
+            deep_search: None,
+            board_view: false,
+            board_columns: Vec::new(),
+            sprint: None,
+            next_sprint: None,
+            sprint_keys: HashSet::new(),
+            sprint_done: 0,
+            sprint_only: false,
+            sprint_picker_selected: 0,
+            burndown: None,
+            velocity: Vec::new(),
+            status_categories: HashMap::new(),
+            flow_stats: None,
+            stats_period: 1,
+            marked: HashSet::new(),
+            visual_anchor: None,
+            visual_base: HashSet::new(),
+            assign_input: String::new(),
+            assign_candidates: Vec::new(),
+            assign_selected: 0,
+            last_assign_query: String::new(),
+            label_input: String::new(),
         }
     }
 
@@ -357,34 +871,70 @@ impl App {
             Ok(id) => self.current_account_id = id,
             Err(e) => self.set_status(format!("Warning: {e}")),
         }
+        match jira::fetch_status_categories(&self.config).await {
+            Ok(categories) => self.status_categories = categories,
+            Err(e) => self.set_status(format!("Warning: {e}")),
+        }
     }
 
     pub async fn refresh(&mut self) {
         self.set_status("Fetching issues...");
         match jira::fetch_issues(&self.config, self.show_all_parents).await {
             Ok(issues) => {
-                self.all_rows = issues
-                    .into_iter()
+                let (pinned, pinned_error) =
+                    match jira::fetch_issues_by_keys(&self.config, &self.pinned_keys).await {
+                        Ok(p) => (p, None),
+                        Err(e) => (Vec::new(), Some(e)),
+                    };
+                let depths = tree_depths(&issues);
+                let pinned_rows = pinned.into_iter().map(|issue| (issue, true, 0));
+                let issue_rows = issues.into_iter().zip(depths).map(|(issue, d)| (issue, false, d));
+                self.all_rows = pinned_rows
+                    .chain(issue_rows)
                     .enumerate()
-                    .map(|(i, issue)| {
-                        let depth = if issue.is_subtask || issue.parent_key.is_some() {
-                            1
-                        } else {
-                            0
-                        };
+                    .map(|(i, (issue, is_pinned, depth))| {
                         let is_context_parent = issue.is_context_parent;
                         DisplayRow {
-                            issue,
+                            issue: Rc::new(issue),
                             depth,
                             is_context_parent,
+                            is_pinned,
                             original_index: i,
+                            from_text_search: false,
                         }
                     })
                     .collect();
-                let count = self.all_rows.len();
-                self.set_status(format!("Loaded {count} issues"));
+                let count = self.all_rows.iter().filter(|r| !r.is_pinned).count();
+                let mut problems = Vec::new();
+                if let Some(e) = pinned_error {
+                    problems.push(format!("pinned: {e}"));
+                }
+                if let Err(e) = self.load_sprint().await {
+                    problems.push(format!("sprint: {e}"));
+                }
+                if problems.is_empty() {
+                    self.set_status(format!("Loaded {count} issues"));
+                } else {
+                    self.set_status(format!("Loaded {count} issues ({})", problems.join("; ")));
+                }
+                self.sync_manual_order();
                 self.sort_rows();
                 self.apply_search_filter();
+                let keys: HashSet<&str> =
+                    self.all_rows.iter().map(|r| r.issue.key.as_str()).collect();
+                // Marks on Jira text matches last until the search is dropped
+                let deep_keys: HashSet<&str> = self
+                    .deep_search
+                    .iter()
+                    .flat_map(|d| d.issues.iter().map(|i| i.key.as_str()))
+                    .collect();
+                self.marked
+                    .retain(|k| keys.contains(k.as_str()) || deep_keys.contains(k.as_str()));
+                let collapsed = self.collapsed_keys.len();
+                self.collapsed_keys.retain(|k| keys.contains(k.as_str()));
+                if self.collapsed_keys.len() != collapsed {
+                    notes::save_collapsed(&self.collapsed_keys);
+                }
             }
             Err(e) => {
                 self.set_status(format!("Error: {e}"));
@@ -396,12 +946,14 @@ impl App {
         if self.selected > 0 {
             self.selected -= 1;
         }
+        self.update_visual_range();
     }
 
     pub fn move_down(&mut self) {
         if !self.rows.is_empty() && self.selected < self.rows.len() - 1 {
             self.selected += 1;
         }
+        self.update_visual_range();
     }
 
     pub fn confirm_open_in_browser(&mut self) {
@@ -498,6 +1050,14 @@ impl App {
     }
 
     pub fn copy_key_to_clipboard(&mut self) {
+        if !self.marked.is_empty() {
+            let keys = self.target_keys();
+            match copy_to_clipboard(&keys.join(", ")) {
+                Ok(()) => self.set_status(format!("Copied {} keys to clipboard", keys.len())),
+                Err(e) => self.set_status(format!("Copy failed: {e}")),
+            }
+            return;
+        }
         if let Some(row) = self.rows.get(self.selected) {
             match copy_to_clipboard(&row.issue.key) {
                 Ok(()) => self.set_status(format!("Copied ticket key '{}' to clipboard", row.issue.key)),
@@ -527,7 +1087,7 @@ impl App {
     }
 
     pub fn highlight_picker_down(&mut self) {
-        let max = if self.current_highlight().is_some() {
+        let max = if self.highlight_removable() {
             HIGHLIGHT_OPTIONS.len() // includes "Remove" at index len
         } else {
             HIGHLIGHT_OPTIONS.len() - 1
@@ -538,20 +1098,32 @@ impl App {
     }
 
     pub fn apply_highlight(&mut self) {
-        if let Some(row) = self.rows.get(self.selected) {
-            let key = row.issue.key.clone();
+        let keys = self.target_keys();
+        for key in &keys {
             if self.highlight_selected < HIGHLIGHT_OPTIONS.len() {
                 let color = HIGHLIGHT_OPTIONS[self.highlight_selected];
-                self.highlighted_keys.insert(key, color.as_str().to_string());
+                self.highlighted_keys.insert(key.clone(), color.as_str().to_string());
             } else {
                 // "Remove" option
-                self.highlighted_keys.remove(&key);
+                self.highlighted_keys.remove(key);
             }
+        }
+        if !keys.is_empty() {
             notes::save_highlights(&self.highlighted_keys);
         }
+        if keys.len() > 1 {
+            self.set_status(format!("Updated highlight of {} issues", keys.len()));
+        }
         self.mode = Mode::Normal;
     }
 
+    /// Whether the picker should offer "Remove": any targeted issue is highlighted.
+    pub fn highlight_removable(&self) -> bool {
+        self.target_keys()
+            .iter()
+            .any(|k| self.highlighted_keys.contains_key(k))
+    }
+
     pub fn cancel_highlight_picker(&mut self) {
         self.mode = Mode::Normal;
     }
@@ -565,10 +1137,26 @@ impl App {
 
     // --- Sort picker ---
 
+    /// Built-in sort keys followed by the configured custom fields.
+    pub fn sort_options(&self) -> Vec<SortCriteria> {
+        let custom = self.config.custom_fields.iter().map(|c| SortCriteria::Custom(c.name.clone()));
+        SortCriteria::ALL.into_iter().chain(custom).collect()
+    }
+
+    /// Plain Jira order, which keeps the tree intact.
+    pub fn is_default_sort(&self) -> bool {
+        matches!(
+            self.sort_keys.as_slice(),
+            [SortKey { criteria: SortCriteria::Default, descending: false }]
+        )
+    }
+
     pub fn open_sort_picker(&mut self) {
-        self.sort_selected = SortCriteria::ALL
+        let primary = &self.sort_keys[0].criteria;
+        self.sort_selected = self
+            .sort_options()
             .iter()
-            .position(|c| *c == self.sort_criteria)
+            .position(|c| c == primary)
             .unwrap_or(0);
         self.mode = Mode::SortPicker;
     }
@@ -580,194 +1168,1725 @@ impl App {
     }
 
     pub fn sort_picker_down(&mut self) {
-        if self.sort_selected < SortCriteria::ALL.len() - 1 {
+        if self.sort_selected + 1 < self.sort_options().len() {
             self.sort_selected += 1;
         }
     }
 
+    /// Sort by the selected option alone and close the picker.
     pub fn apply_sort(&mut self) {
-        self.sort_criteria = SortCriteria::ALL[self.sort_selected];
-        self.config.sort_order = Some(self.sort_criteria.as_str().to_string());
+        let Some(criteria) = self.sort_options().get(self.sort_selected).cloned() else {
+            return;
+        };
+        // Re-selecting the sole key keeps its direction
+        if self.sort_keys.len() != 1 || self.sort_keys[0].criteria != criteria {
+            self.sort_keys = vec![SortKey::new(criteria)];
+        }
+        self.resort();
+        self.mode = Mode::Normal;
+    }
+
+    /// Add the selected option as the next sort key, or drop it if present.
+    pub fn toggle_sort_key(&mut self) {
+        let Some(criteria) = self.sort_options().get(self.sort_selected).cloned() else {
+            return;
+        };
+        if let Some(pos) = self.sort_keys.iter().position(|k| k.criteria == criteria) {
+            self.sort_keys.remove(pos);
+            if self.sort_keys.is_empty() {
+                self.sort_keys.push(SortKey::new(SortCriteria::Default));
+            }
+        } else if criteria == SortCriteria::Default {
+            // Later keys can't break ties after Jira order
+            self.sort_keys = vec![SortKey::new(criteria)];
+        } else {
+            self.sort_keys.retain(|k| k.criteria != SortCriteria::Default);
+            if self.sort_keys.len() >= MAX_SORT_KEYS {
+                self.set_status(format!("At most {MAX_SORT_KEYS} sort keys"));
+                return;
+            }
+            self.sort_keys.push(SortKey::new(criteria));
+        }
+        self.resort();
+    }
+
+    /// Flip the direction of the selected option if it is a sort key.
+    pub fn reverse_sort_key(&mut self) {
+        let Some(criteria) = self.sort_options().get(self.sort_selected).cloned() else {
+            return;
+        };
+        if let Some(key) = self.sort_keys.iter_mut().find(|k| k.criteria == criteria) {
+            key.descending = !key.descending;
+            self.resort();
+        }
+    }
+
+    fn resort(&mut self) {
+        let order: Vec<String> = self
+            .sort_keys
+            .iter()
+            .map(|k| {
+                let dir = if k.descending { "desc" } else { "asc" };
+                format!("{}:{dir}", k.criteria.as_str())
+            })
+            .collect();
+        self.config.sort_order = Some(order.join(","));
         self.config.save();
         self.sort_rows();
         self.apply_search_filter();
-        self.mode = Mode::Normal;
     }
 
     pub fn cancel_sort_picker(&mut self) {
         self.mode = Mode::Normal;
     }
 
-    // --- Column picker ---
-
-    pub fn is_column_visible(&self, col: Column) -> bool {
-        !self.config.hidden_columns.iter().any(|s| s == col.as_str())
-    }
+    // --- Group picker ---
 
-    pub fn open_column_picker(&mut self) {
-        self.column_picker_selected = 0;
-        self.mode = Mode::ColumnPicker;
+    pub fn open_group_picker(&mut self) {
+        self.group_selected = GroupBy::ALL
+            .iter()
+            .position(|g| *g == self.group_by)
+            .unwrap_or(0);
+        self.mode = Mode::GroupPicker;
     }
 
-    pub fn column_picker_up(&mut self) {
-        if self.column_picker_selected > 0 {
-            self.column_picker_selected -= 1;
+    pub fn group_picker_up(&mut self) {
+        if self.group_selected > 0 {
+            self.group_selected -= 1;
         }
     }
 
-    pub fn column_picker_down(&mut self) {
-        if self.column_picker_selected < Column::ALL.len() - 1 {
-            self.column_picker_selected += 1;
+    pub fn group_picker_down(&mut self) {
+        if self.group_selected < GroupBy::ALL.len() - 1 {
+            self.group_selected += 1;
         }
     }
 
-    pub fn toggle_column_visibility(&mut self) {
-        let col = Column::ALL[self.column_picker_selected];
-        let key = col.as_str().to_string();
-        if let Some(pos) = self.config.hidden_columns.iter().position(|s| s == &key) {
-            self.config.hidden_columns.remove(pos);
-        } else {
-            self.config.hidden_columns.push(key);
-        }
+    pub fn apply_group(&mut self) {
+        self.group_by = GroupBy::ALL[self.group_selected];
+        self.config.group_by = match self.group_by {
+            GroupBy::None => None,
+            g => Some(g.as_str().to_string()),
+        };
         self.config.save();
+        self.sort_rows();
+        self.apply_search_filter();
+        self.mode = Mode::Normal;
     }
 
-    pub fn close_column_picker(&mut self) {
+    pub fn cancel_group_picker(&mut self) {
         self.mode = Mode::Normal;
     }
 
-    pub fn sort_rows(&mut self) {
-        match self.sort_criteria {
-            SortCriteria::Default => {
-                self.all_rows.sort_by_key(|r| r.original_index);
-            }
-            SortCriteria::Board => {
-                self.all_rows.sort_by(|a, b| {
-                    let (a_proj, a_num) = split_key(&a.issue.key);
-                    let (b_proj, b_num) = split_key(&b.issue.key);
-                    a_proj.cmp(&b_proj).then(a_num.cmp(&b_num))
-                });
-            }
-            SortCriteria::Priority => {
-                self.all_rows.sort_by(|a, b| {
-                    priority_rank(&b.issue.priority)
-                        .cmp(&priority_rank(&a.issue.priority))
-                        .then(a.original_index.cmp(&b.original_index))
-                });
-            }
-            SortCriteria::Muted => {
-                let muted = &self.muted_keys;
-                self.all_rows.sort_by(|a, b| {
-                    let a_muted = muted.contains(&a.issue.key);
-                    let b_muted = muted.contains(&b.issue.key);
-                    a_muted
-                        .cmp(&b_muted)
-                        .then(a.original_index.cmp(&b.original_index))
-                });
-            }
-            SortCriteria::Highlight => {
-                let highlights = &self.highlighted_keys;
-                self.all_rows.sort_by(|a, b| {
-                    let a_rank = highlight_rank(highlights.get(&a.issue.key).map(|s| s.as_str()));
-                    let b_rank = highlight_rank(highlights.get(&b.issue.key).map(|s| s.as_str()));
-                    a_rank
-                        .cmp(&b_rank)
-                        .then(a.original_index.cmp(&b.original_index))
-                });
-            }
+    /// Section header for `row` under the current group-by, or None when
+    /// not grouping. Pinned rows stay in their own section.
+    pub fn group_label(&self, row: &DisplayRow) -> Option<String> {
+        if row.is_pinned {
+            return None;
         }
+        let issue = &row.issue;
+        let or_none = |s: &str, none: &str| {
+            if s.is_empty() { none.to_string() } else { s.to_string() }
+        };
+        let label = match self.group_by {
+            GroupBy::None => return None,
+            GroupBy::Project => split_key(&issue.key).0.to_string(),
+            GroupBy::Status => or_none(&issue.status, "No status"),
+            GroupBy::StatusCategory => or_none(&issue.status_category, "No category"),
+            GroupBy::Priority => or_none(&issue.priority, "No priority"),
+            GroupBy::IssueType => or_none(&issue.issue_type, "No type"),
+            GroupBy::Epic if issue.issue_type == "Epic" => format!("{} {}", issue.key, issue.summary),
+            GroupBy::Epic => issue.epic.clone().unwrap_or_else(|| "No epic".to_string()),
+            GroupBy::Highlight => match self.highlighted_keys.get(&issue.key).and_then(|s| HighlightColor::from_str(s)) {
+                Some(color) => color.label().to_string(),
+                None => "Not highlighted".to_string(),
+            },
+        };
+        Some(label)
     }
 
-    pub fn toggle_mute(&mut self) {
-        if let Some(row) = self.rows.get(self.selected) {
-            let key = row.issue.key.clone();
-            if !self.muted_keys.remove(&key) {
-                self.muted_keys.insert(key);
+    /// Order of the groups: natural rank where there is one, then by label.
+    fn group_rank(&self, row: &DisplayRow) -> (u8, String) {
+        let label = self.group_label(row).unwrap_or_default();
+        let issue = &row.issue;
+        let rank = match self.group_by {
+            GroupBy::StatusCategory => status_category_rank(&issue.status_category_key),
+            GroupBy::Priority => 6 - priority_rank(&issue.priority),
+            GroupBy::Highlight => {
+                highlight_rank(self.highlighted_keys.get(&issue.key).map(|s| s.as_str()))
             }
-            notes::save_muted(&self.muted_keys);
-        }
+            GroupBy::Epic if issue.issue_type != "Epic" && issue.epic.is_none() => 1,
+            _ => 0,
+        };
+        (rank, label)
     }
 
-    // --- Fuzzy search ---
+    // --- Board view ---
 
-    pub fn start_search(&mut self) {
-        self.search_input.clear();
-        self.mode = Mode::Searching;
+    pub async fn toggle_board(&mut self) {
+        if self.board_view {
+            self.board_view = false;
+            self.set_status("Table view");
+            return;
+        }
+        self.board_view = true;
+        self.set_status("Board view");
+        if let (Some(board_id), true) = (self.config.board_id, self.board_columns.is_empty()) {
+            match jira::fetch_board_columns(&self.config, board_id).await {
+                Ok(columns) => self.board_columns = columns,
+                Err(e) => self.set_status(format!("Warning: board columns: {e}")),
+            }
+        }
     }
 
-    pub fn apply_search_filter(&mut self) {
-        if self.search_input.is_empty() {
-            self.rows = self.all_rows.clone();
-        } else {
-            self.rows = self
-                .all_rows
-                .iter()
-                .filter(|row| {
-                    let haystack = format!("{} {}", row.issue.key, row.issue.summary);
-                    fuzzy_match(&haystack, &self.search_input).is_some()
-                })
-                .cloned()
-                .collect();
-        }
-        if self.rows.is_empty() {
-            self.selected = 0;
-        } else if self.selected >= self.rows.len() {
-            self.selected = self.rows.len() - 1;
+    /// Lanes in board column order, or by status category when no board is
+    /// configured. Statuses the board doesn't map get a lane of their own.
+    pub fn board_lanes(&self) -> Vec<BoardLane> {
+        let mut lanes: Vec<BoardLane> = self
+            .board_columns
+            .iter()
+            .map(|c| BoardLane {
+                name: c.name.clone(),
+                status_ids: c.status_ids.clone(),
+                cards: Vec::new(),
+            })
+            .collect();
+        let configured = lanes.len();
+        let mut extra_rank: Vec<u8> = Vec::new();
+        let mut seen = HashSet::new();
+        for (i, row) in self.rows.iter().enumerate() {
+            let issue = &row.issue;
+            // A pinned issue can also be in the tree
+            if !seen.insert(issue.key.as_str()) {
+                continue;
+            }
+            let lane = lanes.iter().position(|l| l.status_ids.contains(&issue.status_id));
+            let lane = match lane {
+                Some(l) => l,
+                None => {
+                    lanes.push(BoardLane {
+                        name: issue.status.clone(),
+                        status_ids: vec![issue.status_id.clone()],
+                        cards: Vec::new(),
+                    });
+                    extra_rank.push(status_category_rank(&issue.status_category_key));
+                    lanes.len() - 1
+                }
+            };
+            lanes[lane].cards.push(i);
         }
+        // Stable, so statuses keep their order of appearance within a category
+        let mut extra: Vec<(u8, BoardLane)> =
+            extra_rank.into_iter().zip(lanes.drain(configured..)).collect();
+        extra.sort_by_key(|(rank, _)| *rank);
+        lanes.extend(extra.into_iter().map(|(_, lane)| lane));
+        lanes
     }
 
-    pub fn confirm_search(&mut self) {
-        self.mode = Mode::Normal;
+    /// Lane and position within it of the selected row.
+    pub fn board_position(&self, lanes: &[BoardLane]) -> Option<(usize, usize)> {
+        lanes.iter().enumerate().find_map(|(l, lane)| {
+            lane.cards.iter().position(|&c| c == self.selected).map(|p| (l, p))
+        })
     }
 
-    pub fn clear_search(&mut self) {
-        self.search_input.clear();
-        self.rows = self.all_rows.clone();
-        if self.rows.is_empty() {
-            self.selected = 0;
-        } else if self.selected >= self.rows.len() {
-            self.selected = self.rows.len() - 1;
+    pub fn board_move_up(&mut self) {
+        let lanes = self.board_lanes();
+        if let Some((l, p)) = self.board_position(&lanes) {
+            if p > 0 {
+                self.selected = lanes[l].cards[p - 1];
+            }
         }
-        self.mode = Mode::Normal;
     }
 
-    // --- Ticket detail ---
-
-    pub async fn open_ticket_detail(&mut self) {
-        let key = match self.rows.get(self.selected) {
-            Some(row) => row.issue.key.clone(),
-            None => return,
-        };
-        self.set_status(format!("Loading {key}..."));
-        match jira::fetch_issue_detail(&self.config, &key).await {
-            Ok(detail) => {
-                self.detail = Some(detail);
-                self.detail_content_version.set(self.detail_content_version.get() + 1);
-                self.detail_scroll = 0;
-                self.mode = Mode::TicketDetail;
-                self.status_msg.clear();
-                self.detail_status_msg.clear();
-            }
-            Err(e) => {
-                self.set_status(format!("Error: {e}"));
+    pub fn board_move_down(&mut self) {
+        let lanes = self.board_lanes();
+        if let Some((l, p)) = self.board_position(&lanes) {
+            if let Some(&next) = lanes[l].cards.get(p + 1) {
+                self.selected = next;
             }
         }
     }
 
-    pub fn close_detail(&mut self) {
-        self.detail = None;
-        self.detail_scroll = 0;
-        self.detail_comment_selected = None;
-        self.detail_status_msg.clear();
-        if self.notifications_detail_open {
-            self.notifications_detail_open = false;
-            self.mode = Mode::Notifications;
+    /// Select a card in the nearest non-empty lane to the left or right.
+    pub fn board_move_lane(&mut self, forward: bool) {
+        let lanes = self.board_lanes();
+        let Some((l, p)) = self.board_position(&lanes) else {
+            return;
+        };
+        let target = if forward {
+            lanes[l + 1..].iter().find(|lane| !lane.cards.is_empty())
         } else {
-            self.mode = Mode::Normal;
+            lanes[..l].iter().rev().find(|lane| !lane.cards.is_empty())
+        };
+        if let Some(lane) = target {
+            self.selected = lane.cards[p.min(lane.cards.len() - 1)];
         }
     }
 
-    pub fn detail_scroll_up(&mut self) {
+    /// Move the selected card to the next lane by running a transition into
+    /// one of that lane's statuses. Screens with required fields open the form.
+    pub async fn board_move_card(&mut self, forward: bool) {
+        let lanes = self.board_lanes();
+        let Some((l, _)) = self.board_position(&lanes) else {
+            return;
+        };
+        let target = if forward { l + 1 } else { l.wrapping_sub(1) };
+        let Some(lane) = lanes.get(target) else {
+            return;
+        };
+        let key = self.rows[self.selected].issue.key.clone();
+
+        let transitions = match jira::fetch_transitions(&self.config, &key).await {
+            Ok(t) => t,
+            Err(e) => {
+                self.set_status(format!("Error: {e}"));
+                return;
+            }
+        };
+        let Some(transition) = transitions
+            .iter()
+            .find(|t| lane.status_ids.contains(&t.to_status_id))
+            .cloned()
+        else {
+            self.set_status(format!("Error: no transition from {key} to {}", lane.name));
+            return;
+        };
+
+        if !transition.fields.is_empty() {
+            self.transitions = vec![transition];
+            self.transition_selected = 0;
+            self.transition_targets = vec![(key, transitions)];
+            self.mode = Mode::TransitionPicker;
+            self.confirm_transition();
+            return;
+        }
+
+        let empty = serde_json::Map::new();
+        match jira::do_transition(&self.config, &key, &transition.id, &empty, "").await {
+            Ok(()) => {
+                self.refresh().await;
+                self.select_key(&key);
+                self.set_status(format!("Moved {key} to {}", lane.name));
+            }
+            Err(e) => self.set_status(format!("Error: {e}")),
+        }
+    }
+
+    // --- Sprint ---
+
+    /// Fetch the active and next sprint of the configured board, plus the
+    /// keys and progress of the active one.
+    async fn load_sprint(&mut self) -> Result<(), String> {
+        let Some(board_id) = self.config.board_id else {
+            return Ok(());
+        };
+        let sprints = jira::fetch_sprints(&self.config, board_id, "active,future").await?;
+        self.sprint = sprints.iter().find(|s| s.state == "active").cloned();
+        self.next_sprint = sprints.iter().find(|s| s.state == "future").cloned();
+        self.sprint_keys.clear();
+        self.sprint_done = 0;
+        if let Some(sprint) = &self.sprint {
+            let issues = jira::fetch_sprint_issues(&self.config, sprint.id).await?;
+            self.sprint_done = issues.iter().filter(|i| i.status_category_key == "done").count();
+            self.sprint_keys = issues.into_iter().map(|i| i.key).collect();
+        }
+        Ok(())
+    }
+
+    /// Whole days until the active sprint ends, rounded up.
+    pub fn sprint_days_left(&self) -> Option<i64> {
+        let end = jira::parse_timestamp(&self.sprint.as_ref()?.end_date)?;
+        let left = end - jira::now_timestamp();
+        Some(if left <= 0 { 0 } else { (left + 86399) / 86400 })
+    }
+
+    pub fn toggle_sprint_only(&mut self) {
+        if self.sprint.is_none() {
+            self.set_status("No active sprint (set an agile board with `mindful-jira setup`)");
+            return;
+        }
+        self.sprint_only = !self.sprint_only;
+        self.apply_search_filter();
+        if self.sprint_only {
+            self.set_status("Showing current sprint only");
+        } else {
+            self.set_status("Showing all issues");
+        }
+    }
+
+    /// Sprints an issue can be moved into: the active one, then the next.
+    pub fn sprint_options(&self) -> Vec<&jira::Sprint> {
+        self.sprint.iter().chain(self.next_sprint.iter()).collect()
+    }
+
+    pub fn open_sprint_picker(&mut self) {
+        if self.sprint_options().is_empty() {
+            self.set_status("No active or upcoming sprint (set an agile board with `mindful-jira setup`)");
+            return;
+        }
+        if self.target_keys().is_empty() {
+            return;
+        }
+        self.sprint_picker_selected = 0;
+        self.mode = Mode::SprintPicker;
+    }
+
+    pub fn sprint_picker_up(&mut self) {
+        if self.sprint_picker_selected > 0 {
+            self.sprint_picker_selected -= 1;
+        }
+    }
+
+    pub fn sprint_picker_down(&mut self) {
+        if self.sprint_picker_selected + 1 < self.sprint_options().len() {
+            self.sprint_picker_selected += 1;
+        }
+    }
+
+    pub fn cancel_sprint_picker(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    pub async fn move_targets_to_sprint(&mut self) {
+        let Some(sprint) = self.sprint_options().get(self.sprint_picker_selected).map(|s| (*s).clone())
+        else {
+            return;
+        };
+        let keys = self.target_keys();
+        self.mode = Mode::Normal;
+        match jira::move_to_sprint(&self.config, sprint.id, &keys).await {
+            Ok(()) => {
+                let reload = self.load_sprint().await;
+                self.apply_search_filter();
+                let moved = match keys.as_slice() {
+                    [key] => key.clone(),
+                    _ => format!("{} issues", keys.len()),
+                };
+                match reload {
+                    Ok(()) => self.set_status(format!("Moved {moved} to {}", sprint.name)),
+                    Err(e) => self.set_status(format!("Moved {moved} to {} (sprint: {e})", sprint.name)),
+                }
+            }
+            Err(e) => self.set_status(format!("Error: {e}")),
+        }
+    }
+
+    // --- Reports ---
+
+    /// Load the burndown of the active sprint and the velocity of recent ones.
+    pub async fn open_reports(&mut self) {
+        let Some(board_id) = self.config.board_id else {
+            self.set_status("Error: reports need an agile board (run `mindful-jira setup`)");
+            return;
+        };
+        let mut problems = Vec::new();
+        self.burndown = None;
+        if let Some(sprint) = self.sprint.clone() {
+            match jira::fetch_burndown(&self.config, &sprint).await {
+                Ok(b) => self.burndown = Some(b),
+                Err(e) => problems.push(format!("burndown: {e}")),
+            }
+        }
+        let count = self.config.velocity_sprints;
+        match jira::fetch_velocity(&self.config, board_id, count, &self.status_categories).await {
+            Ok(v) => self.velocity = v,
+            Err(e) => problems.push(format!("velocity: {e}")),
+        }
+        if problems.is_empty() {
+            self.set_status("Reports loaded");
+        } else {
+            self.set_status(format!("Error: {}", problems.join("; ")));
+        }
+        self.mode = Mode::Reports;
+    }
+
+    pub fn close_reports(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    // --- Flow stats ---
+
+    pub async fn open_stats(&mut self) {
+        let days = STATS_PERIODS[self.stats_period];
+        match jira::fetch_finished_issues(&self.config, days).await {
+            Ok(issues) => {
+                let stats = flow::summarize(
+                    &issues,
+                    &self.status_categories,
+                    days,
+                    jira::now_timestamp(),
+                );
+                self.set_status(format!("{} issues finished in the last {days} days", issues.len()));
+                self.flow_stats = Some(stats);
+                self.mode = Mode::Stats;
+            }
+            Err(e) => self.set_status(format!("Error: {e}")),
+        }
+    }
+
+    /// Switch to the next shorter or longer period; reload with `open_stats`.
+    pub fn cycle_stats_period(&mut self, longer: bool) {
+        self.stats_period = if longer {
+            (self.stats_period + 1).min(STATS_PERIODS.len() - 1)
+        } else {
+            self.stats_period.saturating_sub(1)
+        };
+    }
+
+    pub fn close_stats(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    // --- Column picker ---
+
+    /// Built-in columns followed by the configured custom fields.
+    fn all_columns(&self) -> Vec<Column> {
+        let custom = self.config.custom_fields.iter().map(|c| Column::Custom(c.name.clone()));
+        Column::ALL.into_iter().chain(custom).collect()
+    }
+
+    /// Chosen columns in display order, whether or not they can show right now.
+    fn column_order(&self) -> Vec<Column> {
+        match &self.config.columns {
+            Some(names) => names
+                .iter()
+                .filter_map(|n| {
+                    Column::from_str(n).or_else(|| {
+                        let custom = self.config.custom_field(n)?;
+                        Some(Column::Custom(custom.name.clone()))
+                    })
+                })
+                .collect(),
+            None => Column::DEFAULT
+                .into_iter()
+                .filter(|c| !self.config.hidden_columns.iter().any(|h| h == c.as_str()))
+                .collect(),
+        }
+    }
+
+    /// Columns the issue table draws, in order.
+    pub fn visible_columns(&self) -> Vec<Column> {
+        self.column_order()
+            .into_iter()
+            .filter(|c| match c {
+                Column::Assignee => self.show_all_parents,
+                Column::Sprint => self.sprint.is_some(),
+                _ => true,
+            })
+            .collect()
+    }
+
+    pub fn is_column_visible(&self, col: &Column) -> bool {
+        self.column_order().contains(col)
+    }
+
+    pub fn column_width(&self, col: &Column) -> u16 {
+        self.config
+            .column_widths
+            .get(col.as_str())
+            .copied()
+            .unwrap_or_else(|| col.default_width())
+    }
+
+    /// Picker entries: chosen columns in order, then the rest.
+    pub fn column_picker_entries(&self) -> Vec<Column> {
+        let mut entries = self.column_order();
+        let rest: Vec<Column> =
+            self.all_columns().into_iter().filter(|c| !entries.contains(c)).collect();
+        entries.extend(rest);
+        entries
+    }
+
+    fn save_column_order(&mut self, order: Vec<Column>) {
+        self.config.columns = Some(order.iter().map(|c| c.as_str().to_string()).collect());
+        self.config.save();
+    }
+
+    pub fn open_column_picker(&mut self) {
+        self.column_picker_selected = 0;
+        self.mode = Mode::ColumnPicker;
+    }
+
+    pub fn column_picker_up(&mut self) {
+        if self.column_picker_selected > 0 {
+            self.column_picker_selected -= 1;
+        }
+    }
+
+    pub fn column_picker_down(&mut self) {
+        if self.column_picker_selected + 1 < self.all_columns().len() {
+            self.column_picker_selected += 1;
+        }
+    }
+
+    pub fn toggle_column_visibility(&mut self) {
+        let Some(col) = self.column_picker_entries().get(self.column_picker_selected).cloned()
+        else {
+            return;
+        };
+        let mut order = self.column_order();
+        match order.iter().position(|c| *c == col) {
+            Some(pos) => {
+                order.remove(pos);
+            }
+            None => order.push(col.clone()),
+        }
+        self.save_column_order(order);
+        // Keep the cursor on the toggled column as it moves between sections
+        if let Some(pos) = self.column_picker_entries().iter().position(|c| *c == col) {
+            self.column_picker_selected = pos;
+        }
+    }
+
+    /// Move the selected column left (`up`) or right in the table.
+    pub fn move_column(&mut self, up: bool) {
+        let mut order = self.column_order();
+        let pos = self.column_picker_selected;
+        if pos >= order.len() {
+            return;
+        }
+        let target = if up {
+            match pos.checked_sub(1) {
+                Some(t) => t,
+                None => return,
+            }
+        } else if pos + 1 < order.len() {
+            pos + 1
+        } else {
+            return;
+        };
+        order.swap(pos, target);
+        self.column_picker_selected = target;
+        self.save_column_order(order);
+    }
+
+    pub fn resize_column(&mut self, delta: i16) {
+        let Some(col) = self.column_picker_entries().get(self.column_picker_selected).cloned()
+        else {
+            return;
+        };
+        let width = self.column_width(&col).saturating_add_signed(delta).clamp(4, 40);
+        if width == col.default_width() {
+            self.config.column_widths.remove(col.as_str());
+        } else {
+            self.config.column_widths.insert(col.as_str().to_string(), width);
+        }
+        self.config.save();
+    }
+
+    pub fn close_column_picker(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    pub fn sort_rows(&mut self) {
+        let mut rows = std::mem::take(&mut self.all_rows);
+        let manual: HashMap<&str, usize> =
+            self.manual_order.iter().enumerate().map(|(i, e)| (e.key.as_str(), i)).collect();
+        rows.sort_by(|a, b| {
+            self.sort_keys
+                .iter()
+                .fold(Ordering::Equal, |ord, key| {
+                    ord.then_with(|| self.compare_rows(key, a, b, &manual))
+                })
+                .then(a.original_index.cmp(&b.original_index))
+        });
+        self.all_rows = rows;
+        // Stable, so the sort above applies within each group
+        if self.group_by != GroupBy::None {
+            let mut rows = std::mem::take(&mut self.all_rows);
+            rows.sort_by_cached_key(|r| self.group_rank(r));
+            self.all_rows = rows;
+        }
+        // Pinned section always stays at the top (stable, so the order within is kept)
+        self.all_rows.sort_by_key(|r| !r.is_pinned);
+    }
+
+    /// Order of two rows under one sort key. Issues without a due date,
+    /// assignee or custom field value go last in either direction.
+    fn compare_rows(
+        &self,
+        key: &SortKey,
+        a: &DisplayRow,
+        b: &DisplayRow,
+        manual: &HashMap<&str, usize>,
+    ) -> Ordering {
+        let (ai, bi) = (&a.issue, &b.issue);
+        let ord = match &key.criteria {
+            SortCriteria::Default => a.original_index.cmp(&b.original_index),
+            SortCriteria::Manual => {
+                let pos = |k: &str| manual.get(k).copied().unwrap_or(usize::MAX);
+                pos(&ai.key).cmp(&pos(&bi.key))
+            }
+            SortCriteria::Board => split_key(&ai.key).cmp(&split_key(&bi.key)),
+            SortCriteria::Priority => priority_rank(&ai.priority).cmp(&priority_rank(&bi.priority)),
+            SortCriteria::Muted => {
+                self.muted_keys.contains(&ai.key).cmp(&self.muted_keys.contains(&bi.key))
+            }
+            SortCriteria::Highlight => {
+                let rank = |k: &String| highlight_rank(self.highlighted_keys.get(k).map(|s| s.as_str()));
+                rank(&ai.key).cmp(&rank(&bi.key))
+            }
+            SortCriteria::Updated => {
+                jira::parse_timestamp(&ai.updated).cmp(&jira::parse_timestamp(&bi.updated))
+            }
+            SortCriteria::Created => ai.created.cmp(&bi.created),
+            SortCriteria::StatusCategory => status_category_rank(&ai.status_category_key)
+                .cmp(&status_category_rank(&bi.status_category_key)),
+            SortCriteria::IssueType => ai.issue_type.to_lowercase().cmp(&bi.issue_type.to_lowercase()),
+            SortCriteria::Due => return compare_present(&ai.due, &bi.due, key.descending),
+            SortCriteria::Assignee => {
+                return compare_present(&ai.assignee, &bi.assignee, key.descending)
+            }
+            SortCriteria::Custom(name) => {
+                let value = |i: &jira::JiraIssue| i.custom.get(name).cloned().unwrap_or_default();
+                return compare_present(&value(ai), &value(bi), key.descending);
+            }
+        };
+        if key.descending {
+            ord.reverse()
+        } else {
+            ord
+        }
+    }
+
+    // --- Manual order ---
+
+    /// Forget issues no fetch has included for `MANUAL_ORDER_EXPIRY` and place
+    /// new ones at the configured end, in Jira order.
+    fn sync_manual_order(&mut self) {
+        let now = jira::now_timestamp();
+        let present: HashSet<&str> = self.all_rows.iter().map(|r| r.issue.key.as_str()).collect();
+        let before = self.manual_order.len();
+        let mut changed = false;
+        for entry in &mut self.manual_order {
+            if present.contains(entry.key.as_str()) && now - entry.last_seen >= MANUAL_SEEN_RESOLUTION {
+                entry.last_seen = now;
+                changed = true;
+            }
+        }
+        // Filters and result limits hide issues too, so only forget long-unseen ones
+        self.manual_order.retain(|e| now - e.last_seen < MANUAL_ORDER_EXPIRY);
+        let known: HashSet<&str> = self.manual_order.iter().map(|e| e.key.as_str()).collect();
+        let mut new_rows: Vec<&DisplayRow> =
+            self.all_rows.iter().filter(|r| !known.contains(r.issue.key.as_str())).collect();
+        new_rows.sort_by_key(|r| r.original_index);
+        let new_entries: Vec<ManualEntry> = new_rows
+            .iter()
+            .map(|r| ManualEntry { key: r.issue.key.clone(), last_seen: now })
+            .collect();
+        if new_entries.is_empty() && self.manual_order.len() == before && !changed {
+            return;
+        }
+        if self.config.manual_new_position.as_deref() == Some("bottom") {
+            self.manual_order.extend(new_entries);
+        } else {
+            self.manual_order.splice(0..0, new_entries);
+        }
+        notes::save_manual_order(&self.manual_order);
+    }
+
+    /// Move the selected issue one row up or down in the manual order.
+    pub fn move_manual(&mut self, up: bool) {
+        if self.sort_keys[0].criteria != SortCriteria::Manual {
+            self.set_status("Sort by Manual (o) to reorder issues");
+            return;
+        }
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        let neighbor = if up {
+            self.selected.checked_sub(1).and_then(|i| self.rows.get(i))
+        } else {
+            self.rows.get(self.selected + 1)
+        };
+        // Pinned rows and other groups keep their own section
+        let Some(neighbor) = neighbor.filter(|n| {
+            n.is_pinned == row.is_pinned && self.group_label(n) == self.group_label(row)
+        }) else {
+            return;
+        };
+        let key = row.issue.key.clone();
+        let a = self.manual_order.iter().position(|e| e.key == key);
+        let b = self.manual_order.iter().position(|e| e.key == neighbor.issue.key);
+        let (Some(a), Some(b)) = (a, b) else {
+            return;
+        };
+        self.manual_order.swap(a, b);
+        notes::save_manual_order(&self.manual_order);
+        self.sort_rows();
+        self.apply_search_filter();
+        self.select_key(&key);
+    }
+
+    /// Mute the targeted issues, or unmute them when all are muted already.
+    pub fn toggle_mute(&mut self) {
+        let keys = self.target_keys();
+        if keys.is_empty() {
+            return;
+        }
+        let unmute = keys.iter().all(|k| self.muted_keys.contains(k));
+        for key in &keys {
+            if unmute {
+                self.muted_keys.remove(key);
+            } else {
+                self.muted_keys.insert(key.clone());
+            }
+        }
+        notes::save_muted(&self.muted_keys);
+        if keys.len() > 1 {
+            let verb = if unmute { "Unmuted" } else { "Muted" };
+            self.set_status(format!("{verb} {} issues", keys.len()));
+        }
+    }
+
+    // --- Multi-select ---
+
+    /// Keys bulk actions apply to: the marked issues in table order, or the selected row.
+    pub fn target_keys(&self) -> Vec<String> {
+        if self.marked.is_empty() {
+            return self
+                .rows
+                .get(self.selected)
+                .map(|r| vec![r.issue.key.clone()])
+                .unwrap_or_default();
+        }
+        let mut seen = HashSet::new();
+        self.known_issues()
+            .map(|i| &i.key)
+            .filter(|k| self.marked.contains(*k) && seen.insert(*k))
+            .cloned()
+            .collect()
+    }
+
+    /// Fetched issues followed by the Jira text matches of the search.
+    fn known_issues(&self) -> impl Iterator<Item = &jira::JiraIssue> {
+        let deep = self.deep_search.iter().flat_map(|d| d.issues.iter());
+        self.all_rows.iter().map(|r| &r.issue).chain(deep).map(|i| i.as_ref())
+    }
+
+    pub fn toggle_mark(&mut self) {
+        self.visual_anchor = None;
+        if let Some(row) = self.rows.get(self.selected) {
+            let key = row.issue.key.clone();
+            if !self.marked.remove(&key) {
+                self.marked.insert(key);
+            }
+            self.move_down();
+        }
+    }
+
+    /// Start a `V` range at the selected row, or finish the one in progress.
+    pub fn toggle_visual(&mut self) {
+        if self.visual_anchor.take().is_some() {
+            self.set_status(format!("{} marked", self.marked.len()));
+            return;
+        }
+        if self.rows.get(self.selected).is_some() {
+            self.visual_base = self.marked.clone();
+            self.visual_anchor = Some(self.selected);
+            self.update_visual_range();
+        }
+    }
+
+    fn update_visual_range(&mut self) {
+        let Some(anchor) = self.visual_anchor else {
+            return;
+        };
+        let (from, to) = (anchor.min(self.selected), anchor.max(self.selected));
+        self.marked = self.visual_base.clone();
+        for row in self.rows.iter().take(to + 1).skip(from) {
+            self.marked.insert(row.issue.key.clone());
+        }
+    }
+
+    /// Whether any rows are marked or a range mark is in progress.
+    pub fn has_marks(&self) -> bool {
+        !self.marked.is_empty() || self.visual_anchor.is_some()
+    }
+
+    pub fn clear_marks(&mut self) {
+        self.marked.clear();
+        self.visual_anchor = None;
+    }
+
+    pub fn start_assign(&mut self) {
+        if self.target_keys().is_empty() {
+            return;
+        }
+        self.assign_input.clear();
+        self.assign_candidates.clear();
+        self.assign_selected = 0;
+        self.last_assign_query.clear();
+        self.cursor_pos = 0;
+        self.mode = Mode::AssignPicker;
+    }
+
+    pub fn cancel_assign(&mut self) {
+        self.assign_input.clear();
+        self.assign_candidates.clear();
+        self.mode = Mode::Normal;
+    }
+
+    pub fn assign_move_up(&mut self) {
+        if self.assign_selected > 0 {
+            self.assign_selected -= 1;
+        }
+    }
+
+    pub fn assign_move_down(&mut self) {
+        if self.assign_selected + 1 < self.assign_candidates.len() {
+            self.assign_selected += 1;
+        }
+    }
+
+    pub async fn fetch_assign_candidates(&mut self) {
+        let query = self.assign_input.trim().to_string();
+        if query.is_empty() {
+            self.last_assign_query.clear();
+            self.assign_candidates.clear();
+            self.assign_selected = 0;
+            return;
+        }
+        if query == self.last_assign_query {
+            return;
+        }
+        self.last_assign_query = query.clone();
+        if let Ok(users) = jira::search_users(&self.config, &query).await {
+            self.assign_candidates = users;
+            self.assign_selected = 0;
+        }
+    }
+
+    pub async fn assign_selected_user(&mut self) {
+        let user = match self.assign_candidates.get(self.assign_selected) {
+            Some(u) => u.clone(),
+            None => return,
+        };
+        let keys = self.target_keys();
+        let mut done = 0;
+        let mut failed = Vec::new();
+        for key in &keys {
+            match jira::assign_issue(&self.config, key, &user.account_id).await {
+                Ok(()) => done += 1,
+                Err(e) => failed.push(format!("{key}: {e}")),
+            }
+        }
+        self.assign_input.clear();
+        self.assign_candidates.clear();
+        self.mode = Mode::Normal;
+        if done > 0 {
+            self.refresh().await;
+        }
+        let action = format!("Assigned to {}", user.display_name);
+        self.set_status(bulk_result_message(&action, done, &failed));
+    }
+
+    pub fn start_adding_label(&mut self) {
+        if self.target_keys().is_empty() {
+            return;
+        }
+        self.label_input.clear();
+        self.cursor_pos = 0;
+        self.mode = Mode::AddingLabel;
+    }
+
+    pub fn cancel_adding_label(&mut self) {
+        self.label_input.clear();
+        self.mode = Mode::Normal;
+    }
+
+    pub async fn add_label_to_targets(&mut self) {
+        let label = self.label_input.trim().to_string();
+        if label.is_empty() {
+            return;
+        }
+        if label.contains(char::is_whitespace) {
+            self.set_status("Error: labels can't contain spaces");
+            return;
+        }
+        let keys = self.target_keys();
+        let mut done = 0;
+        let mut failed = Vec::new();
+        for key in &keys {
+            match jira::add_label(&self.config, key, &label).await {
+                Ok(()) => done += 1,
+                Err(e) => failed.push(format!("{key}: {e}")),
+            }
+        }
+        self.label_input.clear();
+        self.mode = Mode::Normal;
+        let action = format!("Added label '{label}'");
+        self.set_status(bulk_result_message(&action, done, &failed));
+    }
+
+    /// Write the targeted issues with their local notes to a CSV file.
+    pub fn export_targets(&mut self) {
+        let keys = self.target_keys();
+        if keys.is_empty() {
+            return;
+        }
+        let mut seen = HashSet::new();
+        let issues: Vec<&jira::JiraIssue> = self
+            .known_issues()
+            .filter(|i| keys.contains(&i.key) && seen.insert(&i.key))
+            .collect();
+        match export_csv(&issues, &self.notes, &self.long_notes) {
+            Ok(path) => self.set_status(format!(
+                "Exported {} issues to {}",
+                issues.len(),
+                path.display()
+            )),
+            Err(e) => self.set_status(format!("Export failed: {e}")),
+        }
+    }
+
+    // --- Fuzzy search ---
+
+    pub fn start_search(&mut self) {
+        self.search_input.clear();
+        self.drop_deep_search();
+        self.mode = Mode::Searching;
+    }
+
+    /// Forget the Jira text matches, with marks on ones not otherwise loaded.
+    fn drop_deep_search(&mut self) {
+        if self.deep_search.take().is_some() {
+            let keys: HashSet<&str> =
+                self.all_rows.iter().map(|r| r.issue.key.as_str()).collect();
+            self.marked.retain(|k| keys.contains(k.as_str()));
+        }
+    }
+
+    pub fn apply_search_filter(&mut self) {
+        // Row positions change, so a `V` range in progress can't continue
+        self.visual_anchor = None;
+        self.collapsed_summaries = self.compute_collapsed_summaries();
+        let in_scope =
+            |row: &DisplayRow| !self.sprint_only || self.sprint_keys.contains(&row.issue.key);
+        if self.search_input.is_empty() {
+            let parents = self.tree_parents();
+            self.rows = self
+                .all_rows
+                .iter()
+                .filter(|row| in_scope(row) && !self.is_collapsed_away(row, &parents))
+                .cloned()
+                .collect();
+        } else {
+            let query = Query::parse(&self.search_input, &self.config);
+            let text_lower = query.text.to_lowercase();
+            // Jira text matches count only while the text they were run for is unchanged
+            let deep = self
+                .deep_search
+                .as_ref()
+                .filter(|d| !query.text.is_empty() && d.text == query.text);
+            let deep_keys: HashSet<&str> = deep
+                .map(|d| d.issues.iter().map(|i| i.key.as_str()).collect())
+                .unwrap_or_default();
+            let local_keys: HashSet<&str> =
+                self.all_rows.iter().map(|r| r.issue.key.as_str()).collect();
+            // Jira-only matches, placed after everything local
+            let extra_rows: Vec<DisplayRow> = deep
+                .map(|d| d.issues.as_slice())
+                .unwrap_or_default()
+                .iter()
+                .filter(|i| !local_keys.contains(i.key.as_str()))
+                .enumerate()
+                .map(|(i, issue)| DisplayRow {
+                    issue: Rc::clone(issue),
+                    depth: 0,
+                    is_context_parent: false,
+                    is_pinned: false,
+                    original_index: self.all_rows.len() + i,
+                    from_text_search: true,
+                })
+                .collect();
+            let mut matches: Vec<(i32, DisplayRow)> = self
+                .all_rows
+                .iter()
+                .chain(&extra_rows)
+                .filter(|row| in_scope(row))
+                .filter(|row| query.filters.iter().all(|f| self.filter_matches(f, row)))
+                .filter_map(|row| {
+                    let haystack = format!("{} {}", row.issue.key, row.issue.summary);
+                    let lower = haystack.to_lowercase();
+                    if query.excluded.iter().any(|w| lower.contains(w.as_str())) {
+                        return None;
+                    }
+                    if !row.from_text_search {
+                        let in_notes = || {
+                            self.config.search_notes && self.note_contains(&row.issue.key, &text_lower)
+                        };
+                        if let Some(score) = text_match_score(&haystack, &query.text, in_notes) {
+                            return Some((score, row.clone()));
+                        }
+                    }
+                    if !deep_keys.contains(row.issue.key.as_str()) {
+                        return None;
+                    }
+                    let mut row = row.clone();
+                    row.from_text_search = true;
+                    Some((i32::MIN, row))
+                })
+                .collect();
+            if self.search_ranked() {
+                // Best first, but pinned rows and groups keep their sections
+                matches.sort_by_cached_key(|(score, row)| {
+                    (!row.is_pinned, self.group_rank(row), std::cmp::Reverse(*score))
+                });
+            }
+            self.rows = matches.into_iter().map(|(_, row)| row).collect();
+        }
+        if self.rows.is_empty() {
+            self.selected = 0;
+        } else if self.selected >= self.rows.len() {
+            self.selected = self.rows.len() - 1;
+        }
+    }
+
+    /// Whether a row passes one `name:value` search term. An empty value
+    /// (still being typed) passes everything.
+    fn filter_matches(&self, filter: &query::Filter, row: &DisplayRow) -> bool {
+        let value = filter.value.as_str();
+        if value.is_empty() {
+            return true;
+        }
+        let issue = &row.issue;
+        let contains = |s: &str| filter.text_matches(s);
+        let yes = matches!(value, "yes" | "y" | "true");
+        let matched = match &filter.field {
+            Field::Status => contains(&issue.status),
+            Field::Type => contains(&issue.issue_type),
+            Field::Priority => {
+                let (have, want) = (priority_rank(&issue.priority), priority_rank(value));
+                match filter.op {
+                    Op::Contains => contains(&issue.priority),
+                    _ if want == 0 => false,
+                    Op::Ge => have >= want,
+                    Op::Le => have <= want,
+                    Op::Gt => have > want,
+                    Op::Lt => have < want,
+                }
+            }
+            Field::Assignee if value == "none" => issue.assignee.is_empty(),
+            Field::Assignee => contains(&issue.assignee),
+            Field::Label => issue.labels.iter().any(|l| contains(l)),
+            Field::Note => {
+                self.note_contains(&issue.key, value)
+                    || self.note_contains(&issue.key, &value.replace('_', " "))
+            }
+            Field::Highlight => match self.highlighted_keys.get(&issue.key) {
+                Some(color) => value != "none" && (yes || value == "any" || color.starts_with(value)),
+                None => value == "none",
+            },
+            Field::Muted => self.muted_keys.contains(&issue.key) == yes,
+            Field::Custom(name) => issue.custom.get(name).is_some_and(|v| contains(v)),
+        };
+        matched != filter.negated
+    }
+
+    /// Whether the short or long note of `key` contains lowercase `needle`.
+    fn note_contains(&self, key: &str, needle: &str) -> bool {
+        let contains = |n: &String| n.to_lowercase().contains(needle);
+        self.notes.get(key).is_some_and(contains) || self.long_notes.get(key).is_some_and(contains)
+    }
+
+    /// Include note text in the free-text search.
+    pub fn toggle_search_notes(&mut self) {
+        self.config.search_notes = !self.config.search_notes;
+        self.config.save();
+        self.apply_search_filter();
+        self.set_status(if self.config.search_notes {
+            "Search includes notes"
+        } else {
+            "Search ignores notes"
+        });
+    }
+
+    /// Ask Jira for issues whose description or comments contain the search
+    /// text and merge them into the results.
+    pub async fn run_deep_search(&mut self) {
+        let text = self.search_text();
+        if text.is_empty() {
+            self.set_status("Type some text to search Jira for");
+            return;
+        }
+        match jira::search_text(&self.config, &text).await {
+            Ok(issues) => {
+                let count = issues.len();
+                self.deep_search = Some(DeepSearch {
+                    text,
+                    issues: issues.into_iter().map(Rc::new).collect(),
+                });
+                self.apply_search_filter();
+                let shown = self.rows.iter().filter(|r| r.from_text_search).count();
+                self.set_status(format!(
+                    "Jira text search: {count} issues, {shown} more than the local search"
+                ));
+            }
+            Err(e) => self.set_status(format!("Error: {e}")),
+        }
+    }
+
+    /// Rows matched only by the Jira text search, if one is active.
+    pub fn deep_search_count(&self) -> Option<usize> {
+        let text = self.search_text();
+        let active = self.deep_search.as_ref().is_some_and(|d| d.text == text);
+        active.then(|| self.rows.iter().filter(|r| r.from_text_search).count())
+    }
+
+    /// The part of the search that fuzzy-matches key and summary.
+    pub fn search_text(&self) -> String {
+        Query::parse(&self.search_input, &self.config).text
+    }
+
+    /// Whether rows are in best-match order rather than table order.
+    pub fn search_ranked(&self) -> bool {
+        !self.config.search_natural_order && !self.search_text().is_empty()
+    }
+
+    /// Completions for the term being typed: field names, or values of the
+    /// field once its `name:` is written.
+    pub fn search_completions(&self) -> Vec<String> {
+        let token = self.search_input.rsplit(' ').next().unwrap_or("");
+        let (negation, token) = match token.strip_prefix('-') {
+            Some(rest) => ("-", rest),
+            None => ("", token),
+        };
+        let Some((name, partial)) = token.split_once(':') else {
+            if token.is_empty() {
+                return Vec::new();
+            }
+            let lower = token.to_lowercase();
+            let custom = self.config.custom_fields.iter().map(|c| query::custom_term_name(&c.name));
+            return query::FIELD_NAMES
+                .iter()
+                .map(|(n, _, _)| n.to_string())
+                .chain(custom)
+                .filter(|n| n.starts_with(&lower) && *n != lower)
+                .map(|n| format!("{negation}{n}:"))
+                .collect();
+        };
+        let Some(field) = query::field_by_name(name, &self.config) else {
+            return Vec::new();
+        };
+        let op = ["<=", ">=", "<", ">"]
+            .into_iter()
+            .find(|op| field == Field::Priority && partial.starts_with(op))
+            .unwrap_or("");
+        let partial = partial[op.len()..].to_lowercase();
+        let mut values: Vec<String> = match &field {
+            Field::Highlight => ["orange", "green", "any", "none"].map(String::from).to_vec(),
+            Field::Muted => vec!["yes".to_string(), "no".to_string()],
+            Field::Note => Vec::new(),
+            _ => {
+                let mut seen: Vec<String> = Vec::new();
+                for row in &self.all_rows {
+                    let issue = &row.issue;
+                    let row_values: Vec<&str> = match &field {
+                        Field::Status => vec![issue.status.as_str()],
+                        Field::Type => vec![issue.issue_type.as_str()],
+                        Field::Priority => vec![issue.priority.as_str()],
+                        Field::Assignee => vec![issue.assignee.as_str()],
+                        Field::Label => issue.labels.iter().map(String::as_str).collect(),
+                        Field::Custom(n) => issue.custom.get(n).map(String::as_str).into_iter().collect(),
+                        _ => Vec::new(),
+                    };
+                    for v in row_values {
+                        let v = v.to_lowercase().replace(' ', "_");
+                        if !v.is_empty() && !seen.contains(&v) {
+                            seen.push(v);
+                        }
+                    }
+                }
+                if field == Field::Priority {
+                    seen.sort_by_key(|p| std::cmp::Reverse(priority_rank(p)));
+                } else {
+                    seen.sort();
+                }
+                seen
+            }
+        };
+        values.retain(|v| v.starts_with(&partial) && *v != partial);
+        values
+            .into_iter()
+            .map(|v| format!("{negation}{name}:{op}{v}"))
+            .collect()
+    }
+
+    /// Replace the term being typed with its first completion.
+    pub fn complete_search(&mut self) {
+        let Some(completion) = self.search_completions().into_iter().next() else {
+            return;
+        };
+        let start = self.search_input.rfind(' ').map_or(0, |i| i + 1);
+        self.search_input.truncate(start);
+        self.search_input.push_str(&completion);
+        if !completion.ends_with(':') {
+            self.search_input.push(' ');
+        }
+        self.apply_search_filter();
+    }
+
+    // --- Tree collapse ---
+
+    /// Child → parent keys of the tree rows (the pinned section is flat).
+    fn tree_parents(&self) -> HashMap<&str, &str> {
+        self.all_rows
+            .iter()
+            .filter(|r| !r.is_pinned)
+            .filter_map(|r| r.issue.parent_key.as_deref().map(|pk| (r.issue.key.as_str(), pk)))
+            .collect()
+    }
+
+    /// Whether some ancestor of `row` is collapsed.
+    fn is_collapsed_away(&self, row: &DisplayRow, parents: &HashMap<&str, &str>) -> bool {
+        if row.is_pinned {
+            return false;
+        }
+        let mut key = row.issue.key.as_str();
+        for _ in 0..=row.depth {
+            match parents.get(key) {
+                Some(parent) if self.collapsed_keys.contains(*parent) => return true,
+                Some(parent) => key = parent,
+                None => return false,
+            }
+        }
+        false
+    }
+
+    fn has_children(&self, key: &str) -> bool {
+        self.all_rows
+            .iter()
+            .any(|r| !r.is_pinned && r.issue.parent_key.as_deref() == Some(key))
+    }
+
+    /// Descendant count and a per-status summary for a collapsed parent.
+    pub fn collapsed_summary(&self, row: &DisplayRow) -> Option<(usize, String)> {
+        if row.is_pinned {
+            return None;
+        }
+        self.collapsed_summaries.get(&row.issue.key).cloned()
+    }
+
+    /// Roll-ups of all collapsed parents in one pass over the tree rows.
+    fn compute_collapsed_summaries(&self) -> HashMap<String, (usize, String)> {
+        let parents = self.tree_parents();
+        // Descendants per status, in order of appearance
+        let mut tallies: HashMap<&str, Vec<(&str, usize)>> = HashMap::new();
+        for row in self.all_rows.iter().filter(|r| !r.is_pinned) {
+            let mut key = row.issue.key.as_str();
+            for _ in 0..row.depth {
+                let Some(parent) = parents.get(key) else {
+                    break;
+                };
+                if self.collapsed_keys.contains(*parent) {
+                    let statuses = tallies.entry(parent).or_default();
+                    match statuses.iter_mut().find(|(s, _)| *s == row.issue.status) {
+                        Some((_, n)) => *n += 1,
+                        None => statuses.push((&row.issue.status, 1)),
+                    }
+                }
+                key = parent;
+            }
+        }
+        tallies
+            .into_iter()
+            .map(|(key, statuses)| {
+                let count = statuses.iter().map(|(_, n)| n).sum();
+                let summary = statuses
+                    .iter()
+                    .map(|(status, n)| format!("{n} {status}"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                (key.to_string(), (count, summary))
+            })
+            .collect()
+    }
+
+    /// Collapse the selected parent, or move to the parent of a child row.
+    pub fn collapse_selected(&mut self) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        let key = row.issue.key.clone();
+        if row.is_pinned {
+            return;
+        }
+        let parent_key = row.issue.parent_key.clone();
+        if !self.collapsed_keys.contains(&key) && self.has_children(&key) {
+            self.collapsed_keys.insert(key.clone());
+            notes::save_collapsed(&self.collapsed_keys);
+            self.apply_search_filter();
+            self.select_key(&key);
+        } else if let Some(parent) = parent_key {
+            self.select_key(&parent);
+        }
+    }
+
+    pub fn expand_selected(&mut self) {
+        let Some(row) = self.rows.get(self.selected) else {
+            return;
+        };
+        let key = row.issue.key.clone();
+        if self.collapsed_keys.remove(&key) {
+            notes::save_collapsed(&self.collapsed_keys);
+            self.apply_search_filter();
+            self.select_key(&key);
+        }
+    }
+
+    pub fn collapse_all(&mut self) {
+        let key = self.rows.get(self.selected).map(|r| r.issue.key.clone());
+        let parents: HashSet<String> = self
+            .all_rows
+            .iter()
+            .filter(|r| !r.is_pinned)
+            .filter_map(|r| r.issue.parent_key.clone())
+            .collect();
+        self.collapsed_keys.extend(parents);
+        notes::save_collapsed(&self.collapsed_keys);
+        self.apply_search_filter();
+        // Land on the top-level ancestor when the selected row got hidden
+        let tree = self.tree_parents();
+        let mut key = key;
+        while let Some(k) = key.as_deref() {
+            if self.rows.iter().any(|r| r.issue.key == k) {
+                break;
+            }
+            key = tree.get(k).map(|p| p.to_string());
+        }
+        if let Some(k) = key {
+            self.select_key(&k);
+        }
+        self.set_status("Collapsed all");
+    }
+
+    pub fn expand_all(&mut self) {
+        let key = self.rows.get(self.selected).map(|r| r.issue.key.clone());
+        self.collapsed_keys.clear();
+        notes::save_collapsed(&self.collapsed_keys);
+        self.apply_search_filter();
+        if let Some(k) = key {
+            self.select_key(&k);
+        }
+        self.set_status("Expanded all");
+    }
+
+    fn select_key(&mut self, key: &str) {
+        if let Some(idx) = self.rows.iter().position(|r| r.issue.key == key) {
+            self.selected = idx;
+        }
+    }
+
+    pub fn confirm_search(&mut self) {
+        self.mode = Mode::Normal;
+    }
+
+    pub fn clear_search(&mut self) {
+        self.search_input.clear();
+        self.drop_deep_search();
+        self.apply_search_filter();
+        self.mode = Mode::Normal;
+    }
+
+    /// Switch search results between best match first and table order.
+    pub fn toggle_search_ranking(&mut self) {
+        self.config.search_natural_order = !self.config.search_natural_order;
+        self.config.save();
+        self.apply_search_filter();
+        self.selected = 0;
+        self.set_status(if self.config.search_natural_order {
+            "Search results in table order"
+        } else {
+            "Search results by best match"
+        });
+    }
+
+    // --- Ticket detail ---
+
+    pub async fn open_ticket_detail(&mut self) {
+        let key = match self.rows.get(self.selected) {
+            Some(row) => row.issue.key.clone(),
+            None => return,
+        };
+        self.open_detail_for_key(&key).await;
+    }
+
+    /// Open the detail view for any issue key, whether or not it is in `rows`.
+    pub async fn open_detail_for_key(&mut self, key: &str) {
+        self.set_status(format!("Loading {key}..."));
+        match jira::fetch_issue_detail(&self.config, key, &self.known_parents()).await {
+            Ok(detail) => {
+                self.detail = Some(detail);
+                self.detail_content_version.set(self.detail_content_version.get() + 1);
+                self.forget_detail_search();
+                self.detail_scroll = 0;
+                self.detail_back.clear();
+                self.detail_forward.clear();
+                self.mode = Mode::TicketDetail;
+                self.status_msg.clear();
+                self.detail_status_msg.clear();
+                self.record_recent();
+            }
+            Err(e) => {
+                self.set_status(format!("Error: {e}"));
+            }
+        }
+    }
+
+    // --- Quick open ---
+
+    pub fn start_quick_open(&mut self) {
+        self.quick_open_input.clear();
+        self.quick_open_selected = None;
+        self.cursor_pos = 0;
+        self.mode = Mode::QuickOpen;
+    }
+
+    pub fn cancel_quick_open(&mut self) {
+        self.quick_open_input.clear();
+        self.mode = Mode::Normal;
+    }
+
+    /// Recently viewed issues that fuzzy-match the current input, most recent first,
+    /// as many as the prompt shows.
+    pub fn quick_open_candidates(&self) -> Vec<&RecentIssue> {
+        let query = self.quick_open_input.trim();
+        self.recent
+            .iter()
+            .filter(|r| {
+                query.is_empty() || fuzzy_match(&format!("{} {}", r.key, r.summary), query).is_some()
+            })
+            .take(QUICK_OPEN_SHOWN)
+            .collect()
+    }
+
+    pub fn quick_open_input_changed(&mut self) {
+        self.quick_open_selected = None;
+    }
+
+    pub fn quick_open_move_up(&mut self) {
+        self.quick_open_selected = match self.quick_open_selected {
+            None | Some(0) => None,
+            Some(i) => Some(i - 1),
+        };
+    }
+
+    pub fn quick_open_move_down(&mut self) {
+        let count = self.quick_open_candidates().len();
+        if count == 0 {
+            return;
+        }
+        self.quick_open_selected = Some(match self.quick_open_selected {
+            None => 0,
+            Some(i) => (i + 1).min(count - 1),
+        });
+    }
+
+    pub async fn confirm_quick_open(&mut self) {
+        let key = match self.quick_open_selected {
+            Some(i) => self.quick_open_candidates().get(i).map(|r| r.key.clone()),
+            None => parse_issue_key(&self.quick_open_input),
+        };
+        let key = match key {
+            Some(k) => k,
+            None => {
+                self.set_status("Not an issue key or Jira URL");
+                return;
+            }
+        };
+        self.quick_open_input.clear();
+        self.mode = Mode::Normal;
+        self.open_detail_for_key(&key).await;
+    }
+
+    // --- Recently viewed & pinned ---
+
+    /// Move the issue currently shown in the detail view to the front of the recents.
+    fn record_recent(&mut self) {
+        let detail = match &self.detail {
+            Some(d) => d,
+            None => return,
+        };
+        self.recent.retain(|r| r.key != detail.key);
+        self.recent.insert(
+            0,
+            RecentIssue {
+                key: detail.key.clone(),
+                summary: detail.summary.clone(),
+            },
+        );
+        self.recent.truncate(RECENT_LEN);
+        notes::save_recent(&self.recent);
+    }
+
+    pub fn is_pinned(&self, key: &str) -> bool {
+        self.pinned_keys.iter().any(|k| k == key)
+    }
+
+    pub async fn toggle_pin_selected(&mut self) {
+        if let Some(row) = self.rows.get(self.selected) {
+            let key = row.issue.key.clone();
+            let msg = self.toggle_pin(&key).await;
+            self.set_status(msg);
+        }
+    }
+
+    pub async fn toggle_pin_detail(&mut self) {
+        if let Some(detail) = &self.detail {
+            let key = detail.key.clone();
+            let msg = self.toggle_pin(&key).await;
+            self.set_detail_status(msg);
+        }
+    }
+
+    /// Pin or unpin `key`, updating the pinned section in place. Returns a status message.
+    async fn toggle_pin(&mut self, key: &str) -> String {
+        if self.is_pinned(key) {
+            self.pinned_keys.retain(|k| k != key);
+            notes::save_pinned(&self.pinned_keys);
+            self.all_rows.retain(|r| !(r.is_pinned && r.issue.key == key));
+            self.apply_search_filter();
+            return format!("Unpinned {key}");
+        }
+
+        let issue = match self.all_rows.iter().find(|r| r.issue.key == key) {
+            Some(row) => Rc::clone(&row.issue),
+            None => match jira::fetch_issues_by_keys(&self.config, &[key.to_string()]).await {
+                Ok(mut issues) if !issues.is_empty() => Rc::new(issues.remove(0)),
+                Ok(_) => return format!("Error: {key} not found"),
+                Err(e) => return format!("Error: {e}"),
+            },
+        };
+        self.pinned_keys.push(key.to_string());
+        notes::save_pinned(&self.pinned_keys);
+        self.all_rows.push(DisplayRow {
+            issue,
+            depth: 0,
+            is_context_parent: false,
+            is_pinned: true,
+            original_index: self.pinned_keys.len() - 1,
+            from_text_search: false,
+        });
+        self.sort_rows();
+        self.apply_search_filter();
+        format!("Pinned {key}")
+    }
+
+    pub fn close_detail(&mut self) {
+        self.detail = None;
+        self.detail_scroll = 0;
+        self.detail_comment_selected = None;
+        self.detail_tab = DetailTab::Description;
+        self.detail_back.clear();
+        self.detail_forward.clear();
+        self.detail_status_msg.clear();
+        self.forget_detail_search();
+        if self.notifications_detail_open {
+            self.notifications_detail_open = false;
+            self.mode = Mode::Notifications;
+        } else {
+            self.mode = Mode::Normal;
+        }
+    }
+
+    // --- In-detail navigation ---
+
+    pub fn open_related_picker(&mut self) {
+        let has_related = self.detail.as_ref().is_some_and(|d| !d.related.is_empty());
+        if !has_related {
+            self.set_detail_status("No related issues");
+            return;
+        }
+        self.related_selected = 0;
+        self.mode = Mode::DetailRelated;
+    }
+
+    pub fn related_move_up(&mut self) {
+        if self.related_selected > 0 {
+            self.related_selected -= 1;
+        }
+    }
+
+    pub fn related_move_down(&mut self) {
+        let count = self.detail.as_ref().map(|d| d.related.len()).unwrap_or(0);
+        if self.related_selected + 1 < count {
+            self.related_selected += 1;
+        }
+    }
+
+    pub fn close_related_picker(&mut self) {
+        self.mode = Mode::TicketDetail;
+    }
+
+    pub async fn open_selected_related(&mut self) {
+        let key = match self
+            .detail
+            .as_ref()
+            .and_then(|d| d.related.get(self.related_selected))
+        {
+            Some(r) => r.key.clone(),
+            None => return,
+        };
+        self.mode = Mode::TicketDetail;
+        self.open_detail_in_place(&key).await;
+    }
+
+    /// Load `key` into the detail view, pushing the current issue onto the back stack.
+    pub async fn open_detail_in_place(&mut self, key: &str) {
+        self.set_detail_status(format!("Loading {key}..."));
+        match jira::fetch_issue_detail(&self.config, key, &self.known_parents()).await {
+            Ok(detail) => {
+                if let Some(previous) = self.detail.take() {
+                    self.detail_back.push((previous, self.detail_tab, self.detail_scroll));
+                }
+                self.detail_forward.clear();
+                self.show_detail(detail, self.detail_tab, 0);
+                self.detail_status_msg.clear();
+                self.record_recent();
+            }
+            Err(e) => {
+                self.set_detail_status(format!("Error: {e}"));
+            }
+        }
+    }
+
+    pub fn detail_go_back(&mut self) {
+        let (detail, tab, scroll) = match self.detail_back.pop() {
+            Some(entry) => entry,
+            None => return,
+        };
+        if let Some(current) = self.detail.take() {
+            self.detail_forward.push((current, self.detail_tab, self.detail_scroll));
+        }
+        self.show_detail(detail, tab, scroll);
+    }
+
+    pub fn detail_go_forward(&mut self) {
+        let (detail, tab, scroll) = match self.detail_forward.pop() {
+            Some(entry) => entry,
+            None => return,
+        };
+        if let Some(current) = self.detail.take() {
+            self.detail_back.push((current, self.detail_tab, self.detail_scroll));
+        }
+        self.show_detail(detail, tab, scroll);
+    }
+
+    fn show_detail(&mut self, detail: IssueDetail, tab: DetailTab, scroll: u16) {
+        self.detail = Some(detail);
+        self.forget_detail_search();
+        self.detail_content_version.set(self.detail_content_version.get() + 1);
+        self.detail_tab = tab;
+        self.detail_scroll = scroll;
+        self.detail_item_offsets.borrow_mut().clear();
+        self.detail_tab_scroll = [0; DetailTab::ALL.len()];
+        self.detail_item_selected = 0;
+        self.detail_comment_selected = None;
+    }
+
+    pub fn detail_scroll_up(&mut self) {
         self.detail_scroll = self.detail_scroll.saturating_sub(1);
     }
 
@@ -805,7 +2924,104 @@ impl App {
 
     // --- Comment navigation & CRUD ---
 
+    /// Switch tabs, keeping each tab's own scroll position.
+    pub fn set_detail_tab(&mut self, tab: DetailTab) {
+        if tab == self.detail_tab {
+            return;
+        }
+        self.detail_tab_scroll[self.detail_tab.index()] = self.detail_scroll;
+        self.detail_tab = tab;
+        self.detail_scroll = self.detail_tab_scroll[tab.index()];
+        self.detail_item_selected = 0;
+        self.detail_match_selected = 0;
+        // Offsets belong to the previous tab until the next draw
+        self.detail_item_offsets.borrow_mut().clear();
+    }
+
+    pub fn detail_next_tab(&mut self) {
+        let i = self.detail_tab.index();
+        self.set_detail_tab(DetailTab::ALL[(i + 1) % DetailTab::ALL.len()]);
+    }
+
+    pub fn detail_prev_tab(&mut self) {
+        let n = DetailTab::ALL.len();
+        let i = self.detail_tab.index();
+        self.set_detail_tab(DetailTab::ALL[(i + n - 1) % n]);
+    }
+
+    /// Number of selectable items on the current list tab.
+    pub fn detail_item_count(&self) -> usize {
+        let detail = match &self.detail {
+            Some(d) => d,
+            None => return 0,
+        };
+        match self.detail_tab {
+            DetailTab::Links => detail.links().count(),
+            DetailTab::Subtasks => detail.subtasks().count(),
+            DetailTab::PRs => self.detail_prs().len(),
+            _ => 0,
+        }
+    }
+
+    pub fn detail_item_up(&mut self) {
+        if self.detail_item_selected > 0 {
+            self.detail_item_selected -= 1;
+        }
+        self.scroll_to_item(self.detail_item_selected);
+    }
+
+    pub fn detail_item_down(&mut self) {
+        if self.detail_item_selected + 1 < self.detail_item_count() {
+            self.detail_item_selected += 1;
+        }
+        self.scroll_to_item(self.detail_item_selected);
+    }
+
+    /// Parent key of every loaded issue, for resolving detail breadcrumbs.
+    fn known_parents(&self) -> HashMap<String, String> {
+        self.all_rows
+            .iter()
+            .filter_map(|r| Some((r.issue.key.clone(), r.issue.parent_key.clone()?)))
+            .collect()
+    }
+
+    /// Open the parent from the breadcrumb in place.
+    pub async fn detail_open_parent(&mut self) {
+        let key = match self.detail.as_ref().and_then(|d| d.parent()) {
+            Some(p) => p.key.clone(),
+            None => {
+                self.set_detail_status("No parent issue");
+                return;
+            }
+        };
+        self.open_detail_in_place(&key).await;
+    }
+
+    /// Open the selected link or subtask in place, or the selected PR in the browser.
+    pub async fn detail_open_selected_item(&mut self) {
+        let detail = match &self.detail {
+            Some(d) => d,
+            None => return,
+        };
+        let key = match self.detail_tab {
+            DetailTab::Links => detail.links().nth(self.detail_item_selected),
+            DetailTab::Subtasks => detail.subtasks().nth(self.detail_item_selected),
+            DetailTab::PRs => {
+                if let Some(pr) = self.detail_prs().get(self.detail_item_selected) {
+                    let _ = open::that(&pr.html_url);
+                }
+                return;
+            }
+            _ => None,
+        }
+        .map(|r| r.key.clone());
+        if let Some(key) = key {
+            self.open_detail_in_place(&key).await;
+        }
+    }
+
     pub fn detail_next_comment(&mut self) {
+        self.set_detail_tab(DetailTab::Comments);
         let count = self
             .detail
             .as_ref()
@@ -822,6 +3038,7 @@ impl App {
     }
 
     pub fn detail_prev_comment(&mut self) {
+        self.set_detail_tab(DetailTab::Comments);
         self.detail_comment_selected = match self.detail_comment_selected {
             None => None,
             Some(0) => None,
@@ -830,25 +3047,107 @@ impl App {
         self.scroll_to_selected_comment();
     }
 
-    fn scroll_to_selected_comment(&mut self) {
-        let idx = match self.detail_comment_selected {
-            Some(i) => i,
-            None => return,
-        };
-        let offsets = self.detail_comment_offsets.borrow();
-        let line = match offsets.get(idx) {
-            Some(&l) => l,
-            None => return,
-        };
-        let visible_h = self.detail_content_height.get() as usize;
-        if visible_h == 0 {
+    fn scroll_to_selected_comment(&mut self) {
+        if let Some(i) = self.detail_comment_selected {
+            self.scroll_to_item(i);
+        }
+    }
+
+    fn scroll_to_item(&mut self, idx: usize) {
+        let offsets = self.detail_item_offsets.borrow();
+        let line = match offsets.get(idx) {
+            Some(&l) => l,
+            None => return,
+        };
+        let visible_h = self.detail_content_height.get() as usize;
+        if visible_h == 0 {
+            return;
+        }
+        let scroll = self.detail_scroll as usize;
+        if line < scroll {
+            self.detail_scroll = line as u16;
+        } else if line >= scroll + visible_h {
+            // Comments start at the top; list items just come into view
+            self.detail_scroll = if self.detail_tab.is_list() {
+                (line + 1 - visible_h) as u16
+            } else {
+                line as u16
+            };
+        }
+    }
+
+    // --- In-detail search ---
+
+    pub fn start_detail_search(&mut self) {
+        self.detail_search_input.clear();
+        self.detail_match_selected = 0;
+        self.cursor_pos = 0;
+        self.mode = Mode::DetailSearching;
+    }
+
+    /// Keep the query and return to the detail view, where `n`/`N` step through matches.
+    pub fn confirm_detail_search(&mut self) {
+        self.mode = Mode::TicketDetail;
+    }
+
+    pub fn clear_detail_search(&mut self) {
+        self.forget_detail_search();
+        self.mode = Mode::TicketDetail;
+    }
+
+    /// Drop the find query, e.g. when another issue replaces the detail.
+    fn forget_detail_search(&mut self) {
+        self.detail_search_input.clear();
+        self.detail_match_selected = 0;
+    }
+
+    /// Matches of the current query on the last rendered tab.
+    pub fn detail_search_matches(&self) -> Vec<(usize, usize, usize)> {
+        match self.detail_render_cache.borrow().as_ref() {
+            Some(cache) => find_line_matches(&cache.lines, &self.detail_search_input),
+            None => Vec::new(),
+        }
+    }
+
+    /// Jump to the first match at or below the top of the view as the query is typed.
+    pub fn detail_search_changed(&mut self) {
+        let matches = self.detail_search_matches();
+        let top = self.detail_scroll as usize;
+        self.detail_match_selected = matches.iter().position(|m| m.0 >= top).unwrap_or(0);
+        self.scroll_to_match(&matches);
+    }
+
+    pub fn detail_search_next(&mut self) {
+        let matches = self.detail_search_matches();
+        if matches.is_empty() {
+            self.set_detail_status("No matches");
+            return;
+        }
+        self.detail_match_selected = (self.detail_match_selected + 1) % matches.len();
+        self.scroll_to_match(&matches);
+    }
+
+    pub fn detail_search_prev(&mut self) {
+        let matches = self.detail_search_matches();
+        if matches.is_empty() {
+            self.set_detail_status("No matches");
             return;
         }
+        let n = matches.len();
+        self.detail_match_selected = (self.detail_match_selected.min(n - 1) + n - 1) % n;
+        self.scroll_to_match(&matches);
+    }
+
+    fn scroll_to_match(&mut self, matches: &[(usize, usize, usize)]) {
+        let line = match matches.get(self.detail_match_selected) {
+            Some(m) => m.0,
+            None => return,
+        };
+        let visible_h = (self.detail_content_height.get() as usize).max(1);
         let scroll = self.detail_scroll as usize;
-        if line < scroll {
-            self.detail_scroll = line as u16;
-        } else if line >= scroll + visible_h {
-            self.detail_scroll = line as u16;
+        if line < scroll || line >= scroll + visible_h {
+            // Leave a little context above the match
+            self.detail_scroll = line.saturating_sub(visible_h / 3) as u16;
         }
     }
 
@@ -1033,7 +3332,7 @@ impl App {
     }
 
     async fn refresh_detail(&mut self, key: &str) {
-        match jira::fetch_issue_detail(&self.config, key).await {
+        match jira::fetch_issue_detail(&self.config, key, &self.known_parents()).await {
             Ok(detail) => {
                 self.detail = Some(detail);
                 self.detail_content_version.set(self.detail_content_version.get() + 1);
@@ -1204,6 +3503,171 @@ impl App {
             .collect()
     }
 
+    // --- Watchers ---
+
+    pub async fn toggle_watch(&mut self) {
+        let (key, watching) = match &self.detail {
+            Some(d) => (d.key.clone(), d.is_watching),
+            None => return,
+        };
+        let account_id = self.current_account_id.clone();
+        let result = if watching {
+            self.set_detail_status(format!("Unwatching {key}..."));
+            jira::remove_watcher(&self.config, &key, &account_id).await
+        } else {
+            self.set_detail_status(format!("Watching {key}..."));
+            jira::add_watcher(&self.config, &key, &account_id).await
+        };
+        match result {
+            Ok(()) => {
+                self.refresh_detail(&key).await;
+                if self.mode == Mode::DetailWatchers {
+                    self.reload_watchers(&key).await;
+                }
+                if watching {
+                    self.set_detail_status(format!("Stopped watching {key}"));
+                } else {
+                    self.set_detail_status(format!("Watching {key}"));
+                }
+            }
+            Err(e) => self.set_detail_status(format!("Error: {e}")),
+        }
+    }
+
+    pub async fn open_watchers(&mut self) {
+        let key = match &self.detail {
+            Some(d) => d.key.clone(),
+            None => return,
+        };
+        self.set_detail_status("Loading watchers...");
+        match jira::fetch_watchers(&self.config, &key).await {
+            Ok(watchers) => {
+                self.watchers = watchers;
+                self.watchers_selected = 0;
+                self.mode = Mode::DetailWatchers;
+                self.detail_status_msg.clear();
+            }
+            Err(e) => self.set_detail_status(format!("Error: {e}")),
+        }
+    }
+
+    async fn reload_watchers(&mut self, key: &str) {
+        match jira::fetch_watchers(&self.config, key).await {
+            Ok(watchers) => {
+                self.watchers = watchers;
+                if self.watchers_selected >= self.watchers.len() {
+                    self.watchers_selected = self.watchers.len().saturating_sub(1);
+                }
+            }
+            Err(e) => self.set_detail_status(format!("Error: {e}")),
+        }
+    }
+
+    pub fn watchers_move_up(&mut self) {
+        if self.watchers_selected > 0 {
+            self.watchers_selected -= 1;
+        }
+    }
+
+    pub fn watchers_move_down(&mut self) {
+        if self.watchers_selected + 1 < self.watchers.len() {
+            self.watchers_selected += 1;
+        }
+    }
+
+    pub fn close_watchers(&mut self) {
+        self.watchers.clear();
+        self.mode = Mode::TicketDetail;
+    }
+
+    pub async fn remove_selected_watcher(&mut self) {
+        let key = match &self.detail {
+            Some(d) => d.key.clone(),
+            None => return,
+        };
+        let watcher = match self.watchers.get(self.watchers_selected) {
+            Some(w) => w.clone(),
+            None => return,
+        };
+        self.set_detail_status(format!("Removing {}...", watcher.display_name));
+        match jira::remove_watcher(&self.config, &key, &watcher.account_id).await {
+            Ok(()) => {
+                self.refresh_detail(&key).await;
+                self.reload_watchers(&key).await;
+                self.set_detail_status(format!("Removed {} as watcher", watcher.display_name));
+            }
+            Err(e) => self.set_detail_status(format!("Error: {e}")),
+        }
+    }
+
+    pub fn start_adding_watcher(&mut self) {
+        self.watcher_input.clear();
+        self.watcher_candidates.clear();
+        self.watcher_candidate_selected = 0;
+        self.last_watcher_query.clear();
+        self.cursor_pos = 0;
+        self.mode = Mode::DetailAddingWatcher;
+    }
+
+    pub fn cancel_adding_watcher(&mut self) {
+        self.watcher_input.clear();
+        self.watcher_candidates.clear();
+        self.mode = Mode::DetailWatchers;
+    }
+
+    pub fn watcher_candidate_move_up(&mut self) {
+        if self.watcher_candidate_selected > 0 {
+            self.watcher_candidate_selected -= 1;
+        }
+    }
+
+    pub fn watcher_candidate_move_down(&mut self) {
+        if self.watcher_candidate_selected + 1 < self.watcher_candidates.len() {
+            self.watcher_candidate_selected += 1;
+        }
+    }
+
+    pub async fn fetch_watcher_candidates(&mut self) {
+        let query = self.watcher_input.trim().to_string();
+        if query.is_empty() {
+            self.last_watcher_query.clear();
+            self.watcher_candidates.clear();
+            self.watcher_candidate_selected = 0;
+            return;
+        }
+        if query == self.last_watcher_query {
+            return;
+        }
+        self.last_watcher_query = query.clone();
+        if let Ok(users) = jira::search_users(&self.config, &query).await {
+            self.watcher_candidates = users;
+            self.watcher_candidate_selected = 0;
+        }
+    }
+
+    pub async fn add_selected_watcher(&mut self) {
+        let key = match &self.detail {
+            Some(d) => d.key.clone(),
+            None => return,
+        };
+        let user = match self.watcher_candidates.get(self.watcher_candidate_selected) {
+            Some(u) => u.clone(),
+            None => return,
+        };
+        self.set_detail_status(format!("Adding {}...", user.display_name));
+        match jira::add_watcher(&self.config, &key, &user.account_id).await {
+            Ok(()) => {
+                self.watcher_input.clear();
+                self.watcher_candidates.clear();
+                self.mode = Mode::DetailWatchers;
+                self.refresh_detail(&key).await;
+                self.reload_watchers(&key).await;
+                self.set_detail_status(format!("Added {} as watcher", user.display_name));
+            }
+            Err(e) => self.set_detail_status(format!("Error: {e}")),
+        }
+    }
+
     // --- Transitions ---
 
     pub async fn open_transition_picker(&mut self) {
@@ -1212,21 +3676,62 @@ impl App {
             None => return,
         };
         self.set_detail_status("Loading transitions...");
-        match jira::fetch_transitions(&self.config, &key).await {
-            Ok(transitions) => {
-                if transitions.is_empty() {
-                    self.set_detail_status("No transitions available");
-                    return;
-                }
-                self.transitions = transitions;
-                self.transition_selected = 0;
+        match self.load_transitions(vec![key]).await {
+            Ok(()) => {
                 self.mode = Mode::DetailTransition;
                 self.detail_status_msg.clear();
             }
-            Err(e) => {
-                self.set_detail_status(format!("Error: {e}"));
+            Err(e) => self.set_detail_status(e),
+        }
+    }
+
+    /// Open the transition picker from the issue list for the selected row.
+    pub async fn open_list_transition_picker(&mut self) {
+        let keys = self.target_keys();
+        if keys.is_empty() {
+            return;
+        }
+        self.set_status("Loading transitions...");
+        match self.load_transitions(keys).await {
+            Ok(()) => {
+                self.mode = Mode::TransitionPicker;
+                self.status_msg.clear();
             }
+            Err(e) => self.set_status(e),
+        }
+    }
+
+    /// Fetch the transitions of every key and keep those available to all of them,
+    /// matched by name and target status since ids differ between workflows.
+    async fn load_transitions(&mut self, keys: Vec<String>) -> Result<(), String> {
+        let mut per_key = Vec::with_capacity(keys.len());
+        for key in &keys {
+            let transitions = jira::fetch_transitions(&self.config, key)
+                .await
+                .map_err(|e| format!("Error: {key}: {e}"))?;
+            per_key.push(transitions);
+        }
+
+        let mut common = per_key.first().cloned().unwrap_or_default();
+        common.retain(|t| {
+            per_key[1..].iter().all(|other| {
+                other
+                    .iter()
+                    .any(|o| o.name == t.name && o.to_status == t.to_status)
+            })
+        });
+        if common.is_empty() {
+            return Err(if keys.len() > 1 {
+                "No transition available for all selected issues".to_string()
+            } else {
+                "No transitions available".to_string()
+            });
         }
+
+        self.transitions = common;
+        self.transition_selected = 0;
+        self.transition_targets = keys.into_iter().zip(per_key).collect();
+        Ok(())
     }
 
     pub fn transition_move_up(&mut self) {
@@ -1243,46 +3748,186 @@ impl App {
         }
     }
 
+    fn transition_from_list(&self) -> bool {
+        matches!(self.mode, Mode::TransitionPicker | Mode::ConfirmTransition)
+    }
+
+    pub fn set_transition_status(&mut self, msg: impl Into<String>) {
+        if self.transition_from_list() {
+            self.set_status(msg);
+        } else {
+            self.set_detail_status(msg);
+        }
+    }
+
     pub fn cancel_transition(&mut self) {
         self.transitions.clear();
-        self.mode = Mode::TicketDetail;
+        self.transition_targets.clear();
+        self.mode = if self.transition_from_list() {
+            Mode::Normal
+        } else {
+            Mode::TicketDetail
+        };
     }
 
     pub fn confirm_transition(&mut self) {
-        if self.transitions.get(self.transition_selected).is_some() {
-            self.mode = Mode::DetailConfirmTransition;
+        if let Some(t) = self.transitions.get(self.transition_selected) {
+            let form = TransitionForm::new(t);
+            self.cursor_pos = 0;
+            self.transition_form = Some(form);
+            self.mode = if self.transition_from_list() {
+                Mode::ConfirmTransition
+            } else {
+                Mode::DetailConfirmTransition
+            };
         }
     }
 
     pub fn cancel_confirm_transition(&mut self) {
-        self.mode = Mode::DetailTransition;
+        self.transition_form = None;
+        self.mode = if self.transition_from_list() {
+            Mode::TransitionPicker
+        } else {
+            Mode::DetailTransition
+        };
+    }
+
+    pub fn transition_form_focus_next(&mut self) {
+        if let Some(form) = self.transition_form.as_mut() {
+            form.focused = (form.focused + 1) % (form.texts.len() + 1);
+            self.cursor_pos = form.focused_text().map_or(0, |t| t.chars().count());
+        }
+    }
+
+    pub fn transition_form_focus_prev(&mut self) {
+        if let Some(form) = self.transition_form.as_mut() {
+            let n = form.texts.len() + 1;
+            form.focused = (form.focused + n - 1) % n;
+            self.cursor_pos = form.focused_text().map_or(0, |t| t.chars().count());
+        }
+    }
+
+    /// Whether the focused form entry is an option list rather than a text input.
+    pub fn transition_form_on_option(&self) -> bool {
+        let (Some(form), Some(t)) = (
+            &self.transition_form,
+            self.transitions.get(self.transition_selected),
+        ) else {
+            return false;
+        };
+        t.fields.get(form.focused).is_some_and(|f| {
+            matches!(
+                f.kind,
+                TransitionFieldKind::Option | TransitionFieldKind::MultiOption
+            )
+        })
+    }
+
+    pub fn transition_form_cycle_option(&mut self, forward: bool) {
+        let (Some(form), Some(t)) = (
+            self.transition_form.as_mut(),
+            self.transitions.get(self.transition_selected),
+        ) else {
+            return;
+        };
+        let Some(field) = t.fields.get(form.focused) else {
+            return;
+        };
+        let len = field.allowed_values.len();
+        if len == 0 {
+            return;
+        }
+        let current = &mut form.options[form.focused];
+        *current = Some(match *current {
+            None if forward => 0,
+            None => len - 1,
+            Some(i) if forward => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+        });
     }
 
     pub async fn execute_transition(&mut self) {
         let transition = match self.transitions.get(self.transition_selected) {
-            Some(t) => t,
-            None => return,
-        };
-        let key = match &self.detail {
-            Some(d) => d.key.clone(),
+            Some(t) => t.clone(),
             None => return,
         };
         let name = transition.name.clone();
-        let id = transition.id.clone();
-        self.set_detail_status(format!("Transitioning to {name}..."));
-        match jira::do_transition(&self.config, &key, &id).await {
-            Ok(()) => {
-                self.transitions.clear();
-                self.mode = Mode::TicketDetail;
-                self.refresh().await;
-                self.refresh_detail(&key).await;
-                self.set_detail_status(format!("Transitioned to {name}"));
+
+        let mut values = serde_json::Map::new();
+        let mut comment = String::new();
+        if let Some(form) = &self.transition_form {
+            for (i, field) in transition.fields.iter().enumerate() {
+                match field.to_json(form.options[i], &form.texts[i]) {
+                    Ok(value) => {
+                        values.insert(field.id.clone(), value);
+                    }
+                    Err(e) => {
+                        self.set_transition_status(format!("Error: {e}"));
+                        return;
+                    }
+                }
             }
-            Err(e) => {
-                self.set_detail_status(format!("Error: {e}"));
-                self.transitions.clear();
-                self.mode = Mode::TicketDetail;
+            comment = form.comment.clone();
+        }
+
+        self.set_transition_status(format!("Transitioning to {name}..."));
+        let mut done = Vec::new();
+        let mut failed = Vec::new();
+        for (key, available) in &self.transition_targets {
+            let Some(own) = available
+                .iter()
+                .find(|t| t.name == transition.name && t.to_status == transition.to_status)
+            else {
+                failed.push(format!("{key}: transition not available"));
+                continue;
+            };
+            // Only send the fields this issue's screen knows about
+            let mut fields = serde_json::Map::new();
+            if let Some(missing) = own.fields.iter().find(|f| !values.contains_key(&f.id)) {
+                failed.push(format!("{key}: {} is required", missing.name));
+                continue;
+            }
+            for f in &own.fields {
+                fields.insert(f.id.clone(), values[&f.id].clone());
+            }
+            match jira::do_transition(&self.config, key, &own.id, &fields, &comment).await {
+                Ok(()) => done.push(key.clone()),
+                Err(e) => failed.push(format!("{key}: {e}")),
+            }
+        }
+
+        let from_list = self.transition_from_list();
+        let msg = match (done.len(), failed.len()) {
+            (_, 0) if done.len() == 1 => format!("Transitioned {} to {name}", done[0]),
+            (n, 0) => format!("Transitioned {n} issues to {name}"),
+            (0, _) => format!("Error: {}", failed.join("; ")),
+            (n, _) => format!(
+                "Transitioned {n}/{} to {name}; failed: {}",
+                n + failed.len(),
+                failed.join("; ")
+            ),
+        };
+
+        if done.is_empty() {
+            // Stay in the form so the input can be fixed and retried
+            self.set_transition_status(msg);
+            return;
+        }
+
+        self.transitions.clear();
+        self.transition_targets.clear();
+        self.transition_form = None;
+        if from_list {
+            self.mode = Mode::Normal;
+            self.refresh().await;
+            self.set_status(msg);
+        } else {
+            self.mode = Mode::TicketDetail;
+            self.refresh().await;
+            if let Some(key) = done.first() {
+                self.refresh_detail(key).await;
             }
+            self.set_detail_status(msg);
         }
     }
 
@@ -1426,7 +4071,23 @@ impl App {
 
     // --- GitHub PR list ---
 
-    pub async fn open_pr_list(&mut self) {
+    /// PRs of the open issue; empty until they've been fetched for it.
+    pub fn detail_prs(&self) -> &[GithubPR] {
+        let loaded = self.detail.as_ref().map(|d| d.key.as_str()) == self.pr_list_key.as_deref();
+        if loaded {
+            &self.pr_list
+        } else {
+            &[]
+        }
+    }
+
+    /// Whether the PRs tab is showing and its list belongs to another issue.
+    pub fn prs_stale(&self) -> bool {
+        self.detail_tab == DetailTab::PRs
+            && self.detail.as_ref().map(|d| d.key.as_str()) != self.pr_list_key.as_deref()
+    }
+
+    pub async fn load_prs(&mut self) {
         let key = match self.detail.as_ref() {
             Some(d) => d.key.clone(),
             None => return,
@@ -1436,9 +4097,10 @@ impl App {
         match crate::github::fetch_prs_for_ticket(repo, &key).await {
             Ok(prs) => {
                 self.pr_list = prs;
-                self.pr_list_selected = 0;
+                self.pr_list_key = Some(key);
+                self.detail_item_selected = 0;
+                self.detail_content_version.set(self.detail_content_version.get() + 1);
                 self.detail_status_msg.clear();
-                self.mode = Mode::DetailPRList;
             }
             Err(e) => {
                 self.set_detail_status(format!("Error: {e}"));
@@ -1446,30 +4108,6 @@ impl App {
         }
     }
 
-    pub fn close_pr_list(&mut self) {
-        self.pr_list.clear();
-        self.pr_list_selected = 0;
-        self.mode = Mode::TicketDetail;
-    }
-
-    pub fn pr_list_move_up(&mut self) {
-        if self.pr_list_selected > 0 {
-            self.pr_list_selected -= 1;
-        }
-    }
-
-    pub fn pr_list_move_down(&mut self) {
-        if self.pr_list_selected + 1 < self.pr_list.len() {
-            self.pr_list_selected += 1;
-        }
-    }
-
-    pub fn open_selected_pr(&self) {
-        if let Some(pr) = self.pr_list.get(self.pr_list_selected) {
-            let _ = open::that(&pr.html_url);
-        }
-    }
-
     // --- Notifications ---
 
     pub async fn open_notifications(&mut self) {
@@ -1537,15 +4175,19 @@ impl App {
             None => return,
         };
         self.set_status(format!("Loading {key}..."));
-        match jira::fetch_issue_detail(&self.config, &key).await {
+        match jira::fetch_issue_detail(&self.config, &key, &self.known_parents()).await {
             Ok(detail) => {
                 self.detail = Some(detail);
                 self.detail_content_version.set(self.detail_content_version.get() + 1);
+                self.forget_detail_search();
                 self.detail_scroll = 0;
+                self.detail_back.clear();
+                self.detail_forward.clear();
                 self.notifications_detail_open = true;
                 self.mode = Mode::TicketDetail;
                 self.status_msg.clear();
                 self.detail_status_msg.clear();
+                self.record_recent();
             }
             Err(e) => {
                 self.set_status(format!("Error: {e}"));
@@ -1554,30 +4196,117 @@ impl App {
     }
 }
 
+const RECENT_LEN: usize = 30;
+const QUICK_OPEN_SHOWN: usize = 10;
+
+/// Extract an issue key from user input: a bare key (`proj-4711`) or a pasted Jira URL.
+pub fn parse_issue_key(input: &str) -> Option<String> {
+    // The last key wins so `.../browse/PROJ-1?focusedCommentId=...` and
+    // `...?selectedIssue=PROJ-1` both resolve to the issue itself.
+    jira::extract_issue_keys(&input.trim().to_uppercase()).pop()
+}
+
 /// Case-insensitive subsequence fuzzy match. Returns matched char positions if all
 /// needle chars are found in order within the haystack.
 pub fn fuzzy_match(haystack: &str, needle: &str) -> Option<Vec<usize>> {
-    let haystack_lower: Vec<char> = haystack.chars().flat_map(|c| c.to_lowercase()).collect();
-    let needle_lower: Vec<char> = needle.chars().flat_map(|c| c.to_lowercase()).collect();
-
-    let mut positions = Vec::with_capacity(needle_lower.len());
-    let mut hay_idx = 0;
-    for nc in &needle_lower {
-        let mut found = false;
-        while hay_idx < haystack_lower.len() {
-            if haystack_lower[hay_idx] == *nc {
-                positions.push(hay_idx);
-                hay_idx += 1;
-                found = true;
-                break;
-            }
-            hay_idx += 1;
+    fuzzy_score(haystack, needle).map(|(_, positions)| positions)
+}
+
+/// Rank of a free-text match, higher first: fuzzy matches on key and
+/// summary however poor the fit, then issues matching only in their notes.
+fn text_match_score(haystack: &str, needle: &str, in_notes: impl FnOnce() -> bool) -> Option<i32> {
+    match fuzzy_score(haystack, needle) {
+        Some((score, _)) => Some(score.max(1)),
+        None if in_notes() => Some(0),
+        None => None,
+    }
+}
+
+/// fzf-style score of a case-insensitive subsequence match, with the char
+/// positions of the best scoring alignment. Contiguous runs, word starts and
+/// a match at the very start (the issue key) score higher; gaps cost.
+pub fn fuzzy_score(haystack: &str, needle: &str) -> Option<(i32, Vec<usize>)> {
+    const MATCH: i32 = 16;
+    const CONSECUTIVE: i32 = 16;
+    const START: i32 = 20;
+    const BOUNDARY: i32 = 8;
+    const CAMEL: i32 = 8;
+    const GAP_START: i32 = 3;
+    const GAP_EXTEND: i32 = 1;
+    const NONE: i32 = i32::MIN / 2;
+
+    // One lowercase char per char keeps positions aligned with `haystack`
+    let lower = |c: char| c.to_lowercase().next().unwrap_or(c);
+    let hay: Vec<char> = haystack.chars().collect();
+    let hay_lower: Vec<char> = hay.iter().map(|&c| lower(c)).collect();
+    let needle: Vec<char> = needle.chars().filter(|c| !c.is_whitespace()).map(lower).collect();
+    if needle.is_empty() {
+        return Some((0, Vec::new()));
+    }
+    let (n, m) = (needle.len(), hay.len());
+    if n > m {
+        return None;
+    }
+
+    let bonus: Vec<i32> = (0..m)
+        .map(|j| match j.checked_sub(1).map(|p| hay[p]) {
+            None => START,
+            Some(prev) if !prev.is_alphanumeric() && hay[j].is_alphanumeric() => BOUNDARY,
+            Some(prev) if prev.is_lowercase() && hay[j].is_uppercase() => CAMEL,
+            _ => 0,
+        })
+        .collect();
+
+    // score[i][j]: best alignment of needle[..=i] with needle[i] at hay[j]
+    let mut score = vec![vec![NONE; m]; n];
+    let mut from = vec![vec![0usize; m]; n];
+    for j in 0..m {
+        if hay_lower[j] == needle[0] {
+            score[0][j] = MATCH + bonus[j];
         }
-        if !found {
-            return None;
+    }
+    for i in 1..n {
+        // Best predecessor at least one char back, with the gap cost folded in
+        let mut best_gap = (NONE, 0usize);
+        for j in 1..m {
+            if j >= 2 {
+                let k = j - 2;
+                let candidate = score[i - 1][k].saturating_add(GAP_EXTEND * k as i32);
+                if candidate > best_gap.0 {
+                    best_gap = (candidate, k);
+                }
+            }
+            if hay_lower[j] != needle[i] {
+                continue;
+            }
+            let consecutive = score[i - 1][j - 1].saturating_add(CONSECUTIVE);
+            let gap = best_gap.0.saturating_sub(GAP_START + GAP_EXTEND * (j as i32 - 2));
+            let (prev, k) = if consecutive >= gap {
+                (consecutive, j - 1)
+            } else {
+                (gap, best_gap.1)
+            };
+            if prev > NONE / 2 {
+                score[i][j] = prev + MATCH + bonus[j];
+                from[i][j] = k;
+            }
         }
     }
-    Some(positions)
+
+    let (mut j, best) = score[n - 1]
+        .iter()
+        .copied()
+        .enumerate()
+        .max_by_key(|&(j, s)| (s, std::cmp::Reverse(j)))?;
+    if best <= NONE / 2 {
+        return None;
+    }
+    let mut positions = vec![0; n];
+    for i in (0..n).rev() {
+        positions[i] = j;
+        j = from[i][j];
+    }
+    Some((best, positions))
 }
 
 fn split_key(key: &str) -> (&str, u64) {
@@ -1587,6 +4316,39 @@ fn split_key(key: &str) -> (&str, u64) {
     }
 }
 
+/// Periods in days the stats screen can cover.
+const STATS_PERIODS: [i64; 4] = [14, 30, 90, 180];
+
+/// Empty values last; numbers compare as numbers, text ignoring case.
+fn compare_present(a: &str, b: &str, descending: bool) -> Ordering {
+    match (a.is_empty(), b.is_empty()) {
+        (false, true) => Ordering::Less,
+        (true, false) => Ordering::Greater,
+        (true, true) => Ordering::Equal,
+        (false, false) => {
+            let ord = match (a.parse::<f64>(), b.parse::<f64>()) {
+                (Ok(x), Ok(y)) => x.total_cmp(&y),
+                _ => a.to_lowercase().cmp(&b.to_lowercase()),
+            };
+            if descending {
+                ord.reverse()
+            } else {
+                ord
+            }
+        }
+    }
+}
+
+/// Rank by category key, which unlike the name is not localized.
+fn status_category_rank(key: &str) -> u8 {
+    match key {
+        "new" => 0,
+        "indeterminate" => 1,
+        "done" => 2,
+        _ => 3,
+    }
+}
+
 fn highlight_rank(color: Option<&str>) -> u8 {
     match color {
         Some("orange") => 0,
@@ -1595,6 +4357,83 @@ fn highlight_rank(color: Option<&str>) -> u8 {
     }
 }
 
+/// Nesting depth of each issue, counting the ancestors present in `issues`.
+fn tree_depths(issues: &[jira::JiraIssue]) -> Vec<u8> {
+    const MAX_DEPTH: u8 = 8;
+    let parents: HashMap<&str, &str> = issues
+        .iter()
+        .filter_map(|i| i.parent_key.as_deref().map(|pk| (i.key.as_str(), pk)))
+        .collect();
+    issues
+        .iter()
+        .map(|issue| {
+            let mut depth = 0;
+            let mut key = issue.key.as_str();
+            while let Some(parent) = parents.get(key) {
+                if depth == MAX_DEPTH {
+                    break;
+                }
+                depth += 1;
+                key = parent;
+            }
+            depth
+        })
+        .collect()
+}
+
+/// Status line for an action run on several issues, listing the failures.
+fn bulk_result_message(action: &str, done: usize, failed: &[String]) -> String {
+    match (done, failed.len()) {
+        (1, 0) => action.to_string(),
+        (n, 0) => format!("{action} on {n} issues"),
+        (0, _) => format!("Error: {}", failed.join("; ")),
+        (n, f) => format!("{action} on {n}/{}; failed: {}", n + f, failed.join("; ")),
+    }
+}
+
+fn csv_field(s: &str) -> String {
+    if s.contains([',', '"', '\n']) {
+        format!("\"{}\"", s.replace('"', "\"\""))
+    } else {
+        s.to_string()
+    }
+}
+
+fn export_csv(
+    issues: &[&jira::JiraIssue],
+    notes: &HashMap<String, String>,
+    long_notes: &HashMap<String, String>,
+) -> Result<std::path::PathBuf, String> {
+    let dir = dirs::download_dir()
+        .or_else(dirs::home_dir)
+        .ok_or("no home directory")?;
+    let stamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    let path = dir.join(format!("mindful-jira-export-{stamp}.csv"));
+
+    let mut out = String::from("Key,Summary,Type,Status,Priority,Assignee,Reporter,Note,Long note\n");
+    for issue in issues {
+        let fields = [
+            issue.key.as_str(),
+            &issue.summary,
+            &issue.issue_type,
+            &issue.status,
+            &issue.priority,
+            &issue.assignee,
+            &issue.reporter,
+            notes.get(&issue.key).map(String::as_str).unwrap_or(""),
+            long_notes.get(&issue.key).map(String::as_str).unwrap_or(""),
+        ];
+        let line: Vec<String> = fields.iter().map(|f| csv_field(f)).collect();
+        out.push_str(&line.join(","));
+        out.push('\n');
+    }
+    std::fs::write(&path, out).map_err(|e| format!("{e}"))?;
+    Ok(path)
+}
+
 fn copy_to_clipboard(text: &str) -> Result<(), String> {
     use std::io::Write;
     use std::process::{Command, Stdio};
@@ -1621,3 +4460,42 @@ fn copy_to_clipboard(text: &str) -> Result<(), String> {
     child.wait().map_err(|e| format!("{e}"))?;
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(haystack: &str, needle: &str) -> i32 {
+        fuzzy_score(haystack, needle).unwrap().0
+    }
+
+    #[test]
+    fn word_starts_beat_scattered_matches() {
+        assert!(score("ABC-1 Fix login page", "lp") > score("ABC-1 Slow upload", "lp"));
+        assert!(score("ABC-1 Flaky test", "ft") > score("ABC-1 Buffer state", "ft"));
+        // The key prefix counts as a start
+        assert!(score("ABC-1 Fix login page", "abc") > score("XYZ-1 Fix the abacus", "abc"));
+        assert_eq!(fuzzy_score("ABC-1 Fix login page", "lgn").unwrap().1, vec![10, 12, 14]);
+    }
+
+    #[test]
+    fn missing_subsequence_scores_none() {
+        assert!(fuzzy_score("ABC-1 Fix login page", "gl").is_none());
+        assert!(fuzzy_score("ABC-1 Fix login page", "xyz").is_none());
+        assert!(fuzzy_score("abc", "abcd").is_none());
+        assert!(fuzzy_score("ABC-1 Fix login page", "egap").is_none());
+    }
+
+    #[test]
+    fn note_matches_rank_below_any_fuzzy_match() {
+        let far_apart = format!("ABC-1 {}q", "x".repeat(60));
+        assert!(score(&far_apart, "bq") < 0);
+        let poor = text_match_score(&far_apart, "bq", || true);
+        let note = text_match_score("ABC-1 Unrelated", "xyz", || true);
+        assert!(poor.unwrap() > note.unwrap());
+        assert_eq!(note, Some(0));
+        assert_eq!(text_match_score("ABC-1 Unrelated", "xyz", || false), None);
+        // Notes aren't consulted when the summary matches
+        assert!(text_match_score("ABC-1 Fix login", "login", || panic!("notes read")).is_some());
+    }
+}
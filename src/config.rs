@@ -27,6 +27,14 @@ pub fn default_status_filters() -> Vec<StatusFilter> {
     .collect()
 }
 
+/// A custom field under a friendly name.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct CustomField {
+    pub name: String,
+    /// Jira field id, e.g. `customfield_10042`
+    pub id: String,
+}
+
 #[derive(Deserialize, Serialize, Clone)]
 pub struct Config {
     pub jira_url: String,
@@ -34,15 +42,50 @@ pub struct Config {
     pub api_token: String,
     #[serde(default = "default_status_filters")]
     pub status_filters: Vec<StatusFilter>,
+    /// Comma separated sort keys, e.g. `highlight:asc,priority:desc`
     #[serde(default)]
     pub sort_order: Option<String>,
+    /// Where issues new to the manual order land: "top" (default) or "bottom"
+    #[serde(default)]
+    pub manual_new_position: Option<String>,
+    #[serde(default)]
+    pub group_by: Option<String>,
+    /// Columns hidden before `columns` existed; only seeds the default list
     #[serde(default)]
     pub hidden_columns: Vec<String>,
+    /// Visible table columns in display order; None shows the defaults
+    #[serde(default)]
+    pub columns: Option<Vec<String>>,
+    /// Column widths in characters, by column name
+    #[serde(default)]
+    pub column_widths: std::collections::HashMap<String, u16>,
     /// GitHub repo in "owner/repo" format for scoping PR searches (optional)
     #[serde(default)]
     pub github_repo: Option<String>,
     #[serde(default)]
     pub comfortable_spacing: bool,
+    /// Keep search results in table order instead of best match first
+    #[serde(default)]
+    pub search_natural_order: bool,
+    /// Free-text search also looks in short and long notes
+    #[serde(default)]
+    pub search_notes: bool,
+    /// Agile board whose column layout the board view follows (optional)
+    #[serde(default)]
+    pub board_id: Option<u64>,
+    /// Closed sprints shown in the velocity chart
+    #[serde(default = "default_velocity_sprints")]
+    pub velocity_sprints: usize,
+    /// Custom field holding story points; chosen during setup
+    #[serde(default)]
+    pub story_points_field: Option<String>,
+    /// Custom fields fetched with issues; usable as columns, sort keys and in search
+    #[serde(default)]
+    pub custom_fields: Vec<CustomField>,
+}
+
+fn default_velocity_sprints() -> usize {
+    6
 }
 
 pub fn config_dir() -> PathBuf {
@@ -79,6 +122,11 @@ impl Config {
         }
     }
 
+    /// Configured custom field by friendly name, ignoring case.
+    pub fn custom_field(&self, name: &str) -> Option<&CustomField> {
+        self.custom_fields.iter().find(|c| c.name.eq_ignore_ascii_case(name))
+    }
+
     pub fn excluded_status_names(&self) -> Vec<&str> {
         self.status_filters
             .iter()
@@ -0,0 +1,292 @@
+use std::collections::HashMap;
+
+use crate::jira::{self, FlowIssue, HistoryEntry, HistoryItem};
+
+/// A stretch of time an issue spent in one status.
+pub struct StatusSpan {
+    pub status: String,
+    pub status_id: String,
+    pub from: i64,
+    pub to: i64,
+}
+
+/// Status stretches from creation until `now`, oldest first. `history` is
+/// newest first, as in `IssueDetail`; `current` is the status name and id.
+pub fn status_spans(
+    created: &str,
+    current: (&str, &str),
+    history: &[HistoryEntry],
+    now: i64,
+) -> Vec<StatusSpan> {
+    let Some(created) = jira::parse_timestamp(created) else {
+        return Vec::new();
+    };
+    let mut changes: Vec<(i64, &HistoryItem)> = history
+        .iter()
+        .filter_map(|h| {
+            let at = jira::parse_timestamp(&h.created)?;
+            let item = h.items.iter().find(|i| i.field == "status")?;
+            Some((at, item))
+        })
+        .collect();
+    changes.sort_by_key(|(at, _)| *at);
+
+    let mut spans = Vec::with_capacity(changes.len() + 1);
+    let mut status = changes
+        .first()
+        .map_or(current, |(_, item)| (item.from.as_str(), item.from_id.as_str()));
+    let mut since = created;
+    for (at, item) in &changes {
+        spans.push(StatusSpan {
+            status: status.0.to_string(),
+            status_id: status.1.to_string(),
+            from: since,
+            to: *at,
+        });
+        status = (&item.to, &item.to_id);
+        since = *at;
+    }
+    spans.push(StatusSpan {
+        status: status.0.to_string(),
+        status_id: status.1.to_string(),
+        from: since,
+        to: now.max(since),
+    });
+    spans
+}
+
+/// Total seconds per status, in the order the statuses were first entered.
+pub fn time_in_status(spans: &[StatusSpan]) -> Vec<(String, i64)> {
+    let mut totals: Vec<(String, i64)> = Vec::new();
+    for span in spans {
+        let secs = span.to - span.from;
+        match totals.iter_mut().find(|(s, _)| *s == span.status) {
+            Some((_, total)) => *total += secs,
+            None => totals.push((span.status.clone(), secs)),
+        }
+    }
+    totals
+}
+
+/// Seconds from first entering an in-progress status to finally entering a
+/// done one. None until the issue is done. `categories` maps status id to
+/// Jira's category key ("new", "indeterminate", "done").
+pub fn cycle_time(spans: &[StatusSpan], categories: &HashMap<String, String>) -> Option<i64> {
+    let category = |s: &StatusSpan| categories.get(&s.status_id).map(String::as_str);
+    let last = spans.last()?;
+    if category(last) != Some("done") {
+        return None;
+    }
+    let start = spans.iter().find(|s| category(s) == Some("indeterminate"))?;
+    // Start of the final run of done statuses
+    let done = spans
+        .iter()
+        .rev()
+        .take_while(|s| category(s) == Some("done"))
+        .last()?;
+    Some((done.from - start.from).max(0))
+}
+
+/// `3d 4h`, `5h 12m` or `12m`.
+pub fn format_duration(secs: i64) -> String {
+    let mins = secs.max(0) / 60;
+    let (days, hours, mins) = (mins / 1440, mins / 60 % 24, mins % 60);
+    if days > 0 {
+        format!("{days}d {hours}h")
+    } else if hours > 0 {
+        format!("{hours}h {mins}m")
+    } else {
+        format!("{mins}m")
+    }
+}
+
+/// Nearest-rank percentile of ascending `sorted` values.
+fn percentile(sorted: &[i64], p: f64) -> Option<i64> {
+    if sorted.is_empty() {
+        return None;
+    }
+    let rank = ((p / 100.0) * sorted.len() as f64).ceil() as usize;
+    Some(sorted[rank.clamp(1, sorted.len()) - 1])
+}
+
+pub struct FlowStats {
+    pub days: i64,
+    pub median: Option<i64>,
+    pub p85: Option<i64>,
+    pub throughput_per_week: f64,
+    /// Average seconds per status across the finished issues, longest first
+    pub avg_in_status: Vec<(String, i64)>,
+    /// Finished issues with their cycle time, slowest first
+    pub issues: Vec<(String, String, Option<i64>)>,
+}
+
+/// Aggregate flow of the issues finished in the last `days` days.
+pub fn summarize(
+    issues: &[FlowIssue],
+    categories: &HashMap<String, String>,
+    days: i64,
+    now: i64,
+) -> FlowStats {
+    let mut cycle_times = Vec::new();
+    let mut status_totals: Vec<(String, i64)> = Vec::new();
+    let mut per_issue = Vec::with_capacity(issues.len());
+    for issue in issues {
+        let current = (issue.status.as_str(), issue.status_id.as_str());
+        let spans = status_spans(&issue.created, current, &issue.history, now);
+        // A done issue keeps accruing time in its final status; leave that out
+        let open_spans = &spans[..spans.len().saturating_sub(1)];
+        for (status, secs) in time_in_status(open_spans) {
+            match status_totals.iter_mut().find(|(s, _)| *s == status) {
+                Some((_, total)) => *total += secs,
+                None => status_totals.push((status, secs)),
+            }
+        }
+        let cycle = cycle_time(&spans, categories);
+        cycle_times.extend(cycle);
+        per_issue.push((issue.key.clone(), issue.summary.clone(), cycle));
+    }
+    cycle_times.sort_unstable();
+    per_issue.sort_by_key(|(_, _, cycle)| std::cmp::Reverse(*cycle));
+
+    let count = issues.len().max(1) as i64;
+    let mut avg_in_status: Vec<(String, i64)> = status_totals
+        .into_iter()
+        .map(|(status, total)| (status, total / count))
+        .collect();
+    avg_in_status.sort_by_key(|(_, secs)| std::cmp::Reverse(*secs));
+
+    FlowStats {
+        days,
+        median: percentile(&cycle_times, 50.0),
+        p85: percentile(&cycle_times, 85.0),
+        throughput_per_week: issues.len() as f64 * 7.0 / days.max(1) as f64,
+        avg_in_status,
+        issues: per_issue,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn change(created: &str, from: (&str, &str), to: (&str, &str)) -> HistoryEntry {
+        HistoryEntry {
+            author: "Someone".to_string(),
+            created: created.to_string(),
+            items: vec![HistoryItem {
+                field: "status".to_string(),
+                from: from.0.to_string(),
+                to: to.0.to_string(),
+                from_id: from.1.to_string(),
+                to_id: to.1.to_string(),
+            }],
+        }
+    }
+
+    fn categories() -> HashMap<String, String> {
+        [("1", "new"), ("3", "indeterminate"), ("10001", "done"), ("10002", "done")]
+            .into_iter()
+            .map(|(id, cat)| (id.to_string(), cat.to_string()))
+            .collect()
+    }
+
+    const TODO: (&str, &str) = ("To Do", "1");
+    const DOING: (&str, &str) = ("In Progress", "3");
+    const DONE: (&str, &str) = ("Done", "10001");
+
+    #[test]
+    fn empty_history_is_one_span_in_the_current_status() {
+        let created = "2024-03-01T00:00:00.000Z";
+        let start = jira::parse_timestamp(created).unwrap();
+        let spans = status_spans(created, TODO, &[], start + 3600);
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].status, "To Do");
+        assert_eq!(time_in_status(&spans), vec![("To Do".to_string(), 3600)]);
+        assert_eq!(cycle_time(&spans, &categories()), None);
+    }
+
+    #[test]
+    fn unparsable_created_gives_no_spans() {
+        assert!(status_spans("", TODO, &[], 0).is_empty());
+    }
+
+    #[test]
+    fn changes_in_different_offsets_are_ordered_by_instant() {
+        // Newest first; the second entry is an hour earlier than its local time suggests
+        let history = vec![
+            change("2024-03-01T12:30:00.000+0000", DOING, DONE),
+            change("2024-03-01T13:00:00.000+0200", TODO, DOING),
+        ];
+        let created = "2024-03-01T09:00:00.000Z";
+        let end = jira::parse_timestamp("2024-03-02T00:00:00.000Z").unwrap();
+        let spans = status_spans(created, DONE, &history, end);
+        let names: Vec<&str> = spans.iter().map(|s| s.status.as_str()).collect();
+        assert_eq!(names, ["To Do", "In Progress", "Done"]);
+        assert_eq!(
+            time_in_status(&spans[..2]),
+            vec![("To Do".to_string(), 2 * 3600), ("In Progress".to_string(), 90 * 60)]
+        );
+        assert_eq!(cycle_time(&spans, &categories()), Some(90 * 60));
+    }
+
+    #[test]
+    fn time_in_status_adds_up_repeat_visits() {
+        let history = vec![
+            change("2024-03-01T04:00:00.000Z", TODO, DOING),
+            change("2024-03-01T03:00:00.000Z", DOING, TODO),
+            change("2024-03-01T01:00:00.000Z", TODO, DOING),
+        ];
+        let end = jira::parse_timestamp("2024-03-01T05:00:00.000Z").unwrap();
+        let spans = status_spans("2024-03-01T00:00:00.000Z", DOING, &history, end);
+        assert_eq!(
+            time_in_status(&spans),
+            vec![("To Do".to_string(), 2 * 3600), ("In Progress".to_string(), 3 * 3600)]
+        );
+    }
+
+    #[test]
+    fn cycle_time_runs_from_first_start_to_final_done() {
+        let reopened = ("Done", "10002");
+        let history = vec![
+            change("2024-03-04T00:00:00.000Z", DOING, reopened),
+            change("2024-03-03T00:00:00.000Z", DONE, DOING),
+            change("2024-03-02T00:00:00.000Z", DOING, DONE),
+            change("2024-03-01T00:00:00.000Z", TODO, DOING),
+        ];
+        let end = jira::parse_timestamp("2024-03-05T00:00:00.000Z").unwrap();
+        let spans = status_spans("2024-02-28T00:00:00.000Z", reopened, &history, end);
+        assert_eq!(cycle_time(&spans, &categories()), Some(3 * 86400));
+    }
+
+    #[test]
+    fn cycle_time_uses_status_ids_not_names() {
+        // A "Done" that belongs to another workflow's in-progress category
+        let lookalike = ("Done", "3");
+        let history = vec![change("2024-03-01T01:00:00.000Z", TODO, lookalike)];
+        let end = jira::parse_timestamp("2024-03-02T00:00:00.000Z").unwrap();
+        let spans = status_spans("2024-03-01T00:00:00.000Z", lookalike, &history, end);
+        assert_eq!(cycle_time(&spans, &categories()), None);
+    }
+
+    #[test]
+    fn cycle_time_spans_a_leap_day() {
+        let history = vec![
+            change("2024-03-01T00:00:00.000Z", DOING, DONE),
+            change("2024-02-28T00:00:00.000Z", TODO, DOING),
+        ];
+        let end = jira::parse_timestamp("2024-03-02T00:00:00.000Z").unwrap();
+        let spans = status_spans("2024-02-01T00:00:00.000Z", DONE, &history, end);
+        assert_eq!(cycle_time(&spans, &categories()), Some(2 * 86400));
+    }
+
+    #[test]
+    fn percentile_uses_nearest_rank() {
+        assert_eq!(percentile(&[], 50.0), None);
+        assert_eq!(percentile(&[7], 85.0), Some(7));
+        let values: Vec<i64> = (1..=10).collect();
+        assert_eq!(percentile(&values, 50.0), Some(5));
+        assert_eq!(percentile(&values, 85.0), Some(9));
+        assert_eq!(percentile(&values, 100.0), Some(10));
+        assert_eq!(percentile(&values, 0.0), Some(1));
+    }
+}
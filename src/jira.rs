@@ -25,12 +25,27 @@ pub struct JiraIssue {
     pub reporter: String,
     pub priority: String,
     pub status: String,
-    #[allow(dead_code)]
+    pub status_id: String,
+    /// Category display name, e.g. "In Progress"; localized, so only for labels
+    pub status_category: String,
+    /// Category key: "new", "indeterminate" or "done"
+    pub status_category_key: String,
     pub resolution: String,
-    #[allow(dead_code)]
+    /// `YYYY-MM-DD`
     pub created: String,
+    /// ISO timestamp as returned by Jira
+    pub updated: String,
+    /// `YYYY-MM-DD`, empty when unset
+    pub due: String,
+    pub story_points: Option<f64>,
+    pub labels: Vec<String>,
+    pub components: Vec<String>,
+    /// Configured custom fields as text, by friendly name
+    pub custom: std::collections::HashMap<String, String>,
     pub issue_type: String,
     pub parent_key: Option<String>,
+    /// "KEY Summary" of the epic this issue sits under, if known
+    pub epic: Option<String>,
     pub is_subtask: bool,
     pub is_context_parent: bool,
 }
@@ -39,10 +54,76 @@ pub struct IssueDetail {
     pub key: String,
     pub issue_type: String,
     pub status: String,
+    pub status_id: String,
+    pub priority: String,
     pub summary: String,
     pub description: String,
+    /// ISO timestamp as returned by Jira
+    pub created: String,
+    /// Configured custom fields that have a value, in config order
+    pub custom: Vec<(String, String)>,
     pub comments: Vec<Comment>,
     pub reporter_account_id: String,
+    pub is_watching: bool,
+    pub watch_count: u64,
+    /// Parent, subtasks, linked issues and keys mentioned in the text
+    pub related: Vec<RelatedIssue>,
+    /// Parent chain for the breadcrumb, outermost (e.g. the epic) first
+    pub ancestors: Vec<RelatedIssue>,
+    /// Changelog, newest first
+    pub history: Vec<HistoryEntry>,
+    /// Why the changelog couldn't be loaded; `history` is empty then
+    pub history_error: Option<String>,
+}
+
+pub struct HistoryEntry {
+    pub author: String,
+    /// ISO timestamp as returned by Jira
+    pub created: String,
+    pub items: Vec<HistoryItem>,
+}
+
+pub struct HistoryItem {
+    pub field: String,
+    pub from: String,
+    pub to: String,
+    /// Raw values behind `from` / `to`, e.g. status ids
+    pub from_id: String,
+    pub to_id: String,
+}
+
+impl IssueDetail {
+    /// Subtasks, or child issues of an epic
+    pub fn subtasks(&self) -> impl Iterator<Item = &RelatedIssue> {
+        self.related.iter().filter(|r| r.is_child())
+    }
+
+    /// Related issues other than children: parent, issue links and mentions
+    pub fn links(&self) -> impl Iterator<Item = &RelatedIssue> {
+        self.related.iter().filter(|r| !r.is_child())
+    }
+
+    pub fn parent(&self) -> Option<&RelatedIssue> {
+        self.ancestors.last()
+    }
+}
+
+#[derive(Clone)]
+pub struct RelatedIssue {
+    pub key: String,
+    pub summary: String,
+    pub status: String,
+    /// How this issue relates to the open one ("Parent", "blocks", "Mentioned", ...)
+    pub relation: String,
+    pub issue_type: String,
+    /// Only known for parents and children
+    pub assignee: String,
+}
+
+impl RelatedIssue {
+    fn is_child(&self) -> bool {
+        self.relation == "Subtask" || self.relation == "Child"
+    }
 }
 
 pub struct Comment {
@@ -56,6 +137,8 @@ pub struct Comment {
 #[derive(Deserialize)]
 struct SearchResponse {
     issues: Vec<RawIssue>,
+    #[serde(rename = "nextPageToken")]
+    next_page_token: Option<String>,
 }
 
 #[derive(Deserialize)]
@@ -70,11 +153,18 @@ struct RawFields {
     assignee: Option<NameField>,
     reporter: Option<NameField>,
     priority: Option<NameField>,
-    status: Option<NameField>,
+    status: Option<StatusField>,
     resolution: Option<NameField>,
     created: Option<String>,
+    updated: Option<String>,
+    duedate: Option<String>,
+    labels: Option<Vec<String>>,
+    components: Option<Vec<NameField>>,
     parent: Option<ParentField>,
     issuetype: Option<IssueTypeField>,
+    /// Custom fields, keyed by id
+    #[serde(flatten)]
+    extra: std::collections::HashMap<String, serde_json::Value>,
 }
 
 #[derive(Deserialize)]
@@ -83,9 +173,30 @@ struct NameField {
     name: Option<String>,
 }
 
+#[derive(Deserialize)]
+struct StatusField {
+    id: Option<String>,
+    name: Option<String>,
+    #[serde(rename = "statusCategory")]
+    status_category: Option<StatusCategoryField>,
+}
+
+#[derive(Deserialize)]
+struct StatusCategoryField {
+    key: Option<String>,
+    name: Option<String>,
+}
+
 #[derive(Deserialize)]
 struct ParentField {
     key: Option<String>,
+    fields: Option<ParentFields>,
+}
+
+#[derive(Deserialize)]
+struct ParentFields {
+    summary: Option<String>,
+    issuetype: Option<IssueTypeField>,
 }
 
 #[derive(Deserialize)]
@@ -300,61 +411,171 @@ pub async fn fetch_issues(
     let client = reqwest::Client::new();
     let mut issues = search_issues(&client, config, &jql).await?;
 
-    let issue_keys: std::collections::HashSet<String> =
-        issues.iter().map(|i| i.key.clone()).collect();
-    let missing_parents: Vec<String> = issues
-        .iter()
-        .filter_map(|i| i.parent_key.as_ref())
-        .filter(|pk| !issue_keys.contains(*pk))
-        .cloned()
-        .collect::<std::collections::HashSet<_>>()
-        .into_iter()
-        .collect();
-
-    if !missing_parents.is_empty() {
-        let keys_jql = missing_parents
+    // Resolve missing parents level by level (Sub-task → Story → Epic → Initiative)
+    const MAX_PARENT_LEVELS: usize = 4;
+    for _ in 0..MAX_PARENT_LEVELS {
+        let issue_keys: std::collections::HashSet<&str> =
+            issues.iter().map(|i| i.key.as_str()).collect();
+        let missing_parents: Vec<String> = issues
             .iter()
-            .map(|k| format!("\"{k}\""))
-            .collect::<Vec<_>>()
-            .join(", ");
+            .filter_map(|i| i.parent_key.as_deref())
+            .filter(|pk| !issue_keys.contains(pk))
+            .map(str::to_string)
+            .collect::<std::collections::HashSet<_>>()
+            .into_iter()
+            .collect();
+        if missing_parents.is_empty() {
+            break;
+        }
+
+        let keys_jql = quoted_key_list(&missing_parents);
         let parent_jql = if show_all_parents {
             format!("key in ({keys_jql})")
         } else {
             format!("key in ({keys_jql}) AND assignee = currentUser()")
         };
-        if let Ok(mut parents) = search_issues(&client, config, &parent_jql).await {
-            for p in &mut parents {
-                p.parent_key = None;
-                p.is_subtask = false;
-                p.is_context_parent = true;
-            }
-            issues.extend(parents);
+        let Ok(mut parents) = search_issues(&client, config, &parent_jql).await else {
+            break;
+        };
+        if parents.is_empty() {
+            break;
         }
+        for p in &mut parents {
+            p.is_context_parent = true;
+        }
+        issues.extend(parents);
     }
 
+    inherit_epics(&mut issues);
     Ok(build_tree(issues))
 }
 
+/// Sub-tasks only see their story as parent; take the epic from further up.
+fn inherit_epics(issues: &mut [JiraIssue]) {
+    use std::collections::HashMap;
+
+    for _ in 0..4 {
+        let epics: HashMap<String, String> = issues
+            .iter()
+            .filter_map(|i| i.epic.clone().map(|e| (i.key.clone(), e)))
+            .collect();
+        let mut changed = false;
+        for issue in issues.iter_mut().filter(|i| i.epic.is_none()) {
+            if let Some(epic) = issue.parent_key.as_ref().and_then(|pk| epics.get(pk)) {
+                issue.epic = Some(epic.clone());
+                changed = true;
+            }
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// Fetch specific issues by key, regardless of assignee. Results follow `keys` order.
+pub async fn fetch_issues_by_keys(
+    config: &Config,
+    keys: &[String],
+) -> Result<Vec<JiraIssue>, String> {
+    if keys.is_empty() {
+        return Ok(Vec::new());
+    }
+    let client = reqwest::Client::new();
+    let jql = format!("key in ({})", quoted_key_list(keys));
+    let mut issues = search_issues(&client, config, &jql).await?;
+    issues.sort_by_key(|i| keys.iter().position(|k| *k == i.key).unwrap_or(usize::MAX));
+    Ok(issues)
+}
+
+/// Issues whose summary, description or comments contain `text`, using
+/// Jira's `text ~` search, most recently updated first.
+pub async fn search_text(config: &Config, text: &str) -> Result<Vec<JiraIssue>, String> {
+    let client = reqwest::Client::new();
+    search_issues(&client, config, &text_search_jql(text)).await
+}
+
+/// JQL for a `text ~` search of `text` taken literally: Lucene operators
+/// (`-`, `*`, `?`, ...) are escaped, then the result is quoted for JQL.
+fn text_search_jql(text: &str) -> String {
+    const LUCENE_SPECIAL: &str = "+-&|!(){}[]^\"~*?:\\/";
+    let mut lucene = String::new();
+    for c in text.chars() {
+        if LUCENE_SPECIAL.contains(c) {
+            lucene.push('\\');
+        }
+        lucene.push(c);
+    }
+    let escaped = lucene.replace('\\', "\\\\").replace('"', "\\\"");
+    format!("text ~ \"{escaped}\" ORDER BY updated DESC")
+}
+
+fn quoted_key_list(keys: &[String]) -> String {
+    keys.iter()
+        .map(|k| format!("\"{k}\""))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// First page (up to 100 issues) of a search.
 async fn search_issues(
     client: &reqwest::Client,
     config: &Config,
     jql: &str,
 ) -> Result<Vec<JiraIssue>, String> {
-    let fields =
-        "key,summary,assignee,reporter,priority,status,resolution,created,parent,issuetype,subtasks";
+    Ok(search_issues_page(client, config, jql, None).await?.0)
+}
+
+/// Every issue of a search, following `nextPageToken`.
+async fn search_all_issues(
+    client: &reqwest::Client,
+    config: &Config,
+    jql: &str,
+) -> Result<Vec<JiraIssue>, String> {
+    let mut issues = Vec::new();
+    let mut token = None;
+    loop {
+        let (page, next) = search_issues_page(client, config, jql, token.as_deref()).await?;
+        issues.extend(page);
+        match next {
+            Some(t) => token = Some(t),
+            None => return Ok(issues),
+        }
+    }
+}
+
+/// One page of a search and the token for the next one, if any.
+async fn search_issues_page(
+    client: &reqwest::Client,
+    config: &Config,
+    jql: &str,
+    page_token: Option<&str>,
+) -> Result<(Vec<JiraIssue>, Option<String>), String> {
+    let mut fields = "key,summary,assignee,reporter,priority,status,resolution,created,updated,duedate,labels,components,parent,issuetype,subtasks".to_string();
+    if let Some(points) = &config.story_points_field {
+        fields.push(',');
+        fields.push_str(points);
+    }
+    for custom in &config.custom_fields {
+        fields.push(',');
+        fields.push_str(&custom.id);
+    }
     let url = format!(
         "{}/rest/api/3/search/jql",
         config.jira_url.trim_end_matches('/')
     );
 
+    let mut query = vec![
+        ("jql", jql),
+        ("fields", fields.as_str()),
+        ("maxResults", "100"),
+    ];
+    if let Some(token) = page_token {
+        query.push(("nextPageToken", token));
+    }
     let resp = client
         .get(&url)
         .basic_auth(&config.email, Some(&config.api_token))
-        .query(&[
-            ("jql", jql),
-            ("fields", fields),
-            ("maxResults", "100"),
-        ])
+        .query(&query)
         .send()
         .await
         .map_err(|e| format!("HTTP request failed: {e}"))?;
@@ -370,37 +591,81 @@ async fn search_issues(
         .await
         .map_err(|e| format!("Failed to parse Jira response: {e}"))?;
 
-    Ok(search
+    let issues = search
         .issues
         .into_iter()
         .map(|raw| {
             let f = raw.fields;
+            let (status, status_id, category) = match f.status {
+                Some(s) => (s.name.unwrap_or_default(), s.id.unwrap_or_default(), s.status_category),
+                None => (String::new(), String::new(), None),
+            };
+            let (status_category, status_category_key) = match category {
+                Some(c) => (c.name.unwrap_or_default(), c.key.unwrap_or_default()),
+                None => (String::new(), String::new()),
+            };
+            let epic = f.parent.as_ref().and_then(|p| {
+                let fields = p.fields.as_ref()?;
+                let is_epic = fields.issuetype.as_ref()?.name.as_deref() == Some("Epic");
+                let key = p.key.as_ref()?;
+                is_epic.then(|| format!("{} {}", key, fields.summary.as_deref().unwrap_or("")))
+            });
             JiraIssue {
                 key: raw.key,
                 summary: f.summary.unwrap_or_default(),
                 assignee: f.assignee.and_then(|a| a.name).unwrap_or_default(),
                 reporter: f.reporter.and_then(|r| r.name).unwrap_or_default(),
                 priority: f.priority.and_then(|p| p.name).unwrap_or_default(),
-                status: f.status.and_then(|s| s.name).unwrap_or_default(),
+                status,
+                status_id,
+                status_category,
+                status_category_key,
                 resolution: f
                     .resolution
                     .and_then(|r| r.name)
                     .unwrap_or_else(|| "Unresolved".to_string()),
                 created: f.created.map(|c| format_date(&c)).unwrap_or_default(),
+                updated: f.updated.unwrap_or_default(),
+                due: f.duedate.unwrap_or_default(),
+                story_points: config
+                    .story_points_field
+                    .as_ref()
+                    .and_then(|id| f.extra.get(id))
+                    .and_then(|v| v.as_f64()),
+                labels: f.labels.unwrap_or_default(),
+                components: f
+                    .components
+                    .unwrap_or_default()
+                    .into_iter()
+                    .filter_map(|c| c.name)
+                    .collect(),
+                custom: config
+                    .custom_fields
+                    .iter()
+                    .filter_map(|c| {
+                        let text = custom_field_text(f.extra.get(&c.id)?);
+                        (!text.is_empty()).then(|| (c.name.clone(), text))
+                    })
+                    .collect(),
                 issue_type: f.issuetype.as_ref().and_then(|t| t.name.clone()).unwrap_or_default(),
                 parent_key: f.parent.and_then(|p| p.key),
+                epic,
                 is_subtask: f.issuetype.and_then(|t| t.subtask).unwrap_or(false),
                 is_context_parent: false,
             }
         })
-        .collect())
+        .collect();
+    Ok((issues, search.next_page_token))
 }
 
 // --- Issue detail ---
 
+/// `known_parents` maps keys of already loaded issues to their parent key,
+/// so the breadcrumb can usually be fetched in a single search.
 pub async fn fetch_issue_detail(
     config: &Config,
     key: &str,
+    known_parents: &std::collections::HashMap<String, String>,
 ) -> Result<IssueDetail, String> {
     let url = format!(
         "{}/rest/api/3/issue/{}",
@@ -408,11 +673,17 @@ pub async fn fetch_issue_detail(
         key
     );
 
+    let mut field_list = "summary,description,comment,issuetype,status,priority,reporter,parent,subtasks,issuelinks,watches,created".to_string();
+    for custom in &config.custom_fields {
+        field_list.push(',');
+        field_list.push_str(&custom.id);
+    }
+
     let client = reqwest::Client::new();
     let resp = client
         .get(&url)
         .basic_auth(&config.email, Some(&config.api_token))
-        .query(&[("fields", "summary,description,comment,issuetype,status,reporter")])
+        .query(&[("fields", field_list.as_str())])
         .send()
         .await
         .map_err(|e| format!("HTTP request failed: {e}"))?;
@@ -462,7 +733,7 @@ pub async fn fetch_issue_detail(
                         .to_string(),
                     created: c["created"]
                         .as_str()
-                        .map(|s| format_date(s))
+                        .map(format_date)
                         .unwrap_or_default(),
                     body: adf_to_text(&c["body"]).trim().to_string(),
                 })
@@ -484,23 +755,342 @@ pub async fn fetch_issue_detail(
         .as_str()
         .unwrap_or("")
         .to_string();
+    let status_id = fields["status"]["id"].as_str().unwrap_or("").to_string();
+
+    let priority = fields["priority"]["name"]
+        .as_str()
+        .unwrap_or("")
+        .to_string();
 
     let reporter_account_id = fields["reporter"]["accountId"]
         .as_str()
         .unwrap_or("")
         .to_string();
 
+    let created = fields["created"].as_str().unwrap_or("").to_string();
+    let custom = config
+        .custom_fields
+        .iter()
+        .map(|c| (c.name.clone(), custom_field_text(&fields[c.id.as_str()])))
+        .filter(|(_, text)| !text.is_empty())
+        .collect();
+    let is_watching = fields["watches"]["isWatching"].as_bool().unwrap_or(false);
+    let watch_count = fields["watches"]["watchCount"].as_u64().unwrap_or(0);
+
+    let mut related = collect_related(key, fields, &description, &comments);
+    // History is secondary: a failure shows in its tab instead of blocking the issue
+    let (history, history_error) = match fetch_changelog(&client, config, key).await {
+        Ok(history) => (history, None),
+        Err(e) => (Vec::new(), Some(e)),
+    };
+
+    // The subtasks field lacks assignees and epics don't list their children
+    let parent_key = fields["parent"]["key"].as_str();
+    // Without the family the issue still opens, with the fields' subtasks and no breadcrumb
+    let ancestors = match fetch_family(&client, config, key, parent_key, known_parents).await {
+        Ok((children, ancestors)) => {
+            related.retain(|r| !r.is_child());
+            let at = related.iter().position(|r| r.relation == "Parent").map_or(0, |i| i + 1);
+            related.splice(at..at, children);
+            ancestors
+        }
+        Err(_) => Vec::new(),
+    };
+    // Ancestors above the parent, so every breadcrumb entry can be opened
+    let above_parent = ancestors.len().saturating_sub(1);
+    related.splice(
+        0..0,
+        ancestors[..above_parent].iter().map(|a| RelatedIssue {
+            relation: "Ancestor".to_string(),
+            ..a.clone()
+        }),
+    );
+
     Ok(IssueDetail {
         key: key.to_string(),
         issue_type,
         status,
+        status_id,
+        priority,
         summary,
         description,
+        created,
+        custom,
         comments,
         reporter_account_id,
+        is_watching,
+        watch_count,
+        related,
+        ancestors,
+        history,
+        history_error,
+    })
+}
+
+/// Full change history of `key`, newest first. The changelog embedded in
+/// issue responses is capped, so this pages the changelog endpoint.
+async fn fetch_changelog(
+    client: &reqwest::Client,
+    config: &Config,
+    key: &str,
+) -> Result<Vec<HistoryEntry>, String> {
+    let url = format!(
+        "{}/rest/api/3/issue/{}/changelog",
+        config.jira_url.trim_end_matches('/'),
+        key
+    );
+    let mut history = Vec::new();
+    let mut start_at = 0;
+    loop {
+        let resp = client
+            .get(&url)
+            .basic_auth(&config.email, Some(&config.api_token))
+            .query(&[("startAt", start_at.to_string()), ("maxResults", "100".to_string())])
+            .send()
+            .await
+            .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Jira API error {status}: {body}"));
+        }
+
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse changelog: {e}"))?;
+        let values = json["values"].as_array().cloned().unwrap_or_default();
+        start_at += values.len();
+        history.extend(parse_histories(&values));
+        let total = json["total"].as_u64().unwrap_or(0) as usize;
+        if values.is_empty() || json["isLast"].as_bool().unwrap_or(start_at >= total) {
+            break;
+        }
+    }
+    sort_history(&mut history);
+    Ok(history)
+}
+
+/// History embedded in an issue fetched with `expand=changelog`, newest first.
+fn parse_changelog(issue: &serde_json::Value) -> Vec<HistoryEntry> {
+    let mut history = issue["changelog"]["histories"]
+        .as_array()
+        .map(|arr| parse_histories(arr))
+        .unwrap_or_default();
+    sort_history(&mut history);
+    history
+}
+
+fn sort_history(history: &mut [HistoryEntry]) {
+    history.sort_by_cached_key(|h| std::cmp::Reverse(parse_timestamp(&h.created)));
+}
+
+fn parse_histories(histories: &[serde_json::Value]) -> Vec<HistoryEntry> {
+    histories
+        .iter()
+        .map(|h| HistoryEntry {
+            author: h["author"]["displayName"]
+                .as_str()
+                .unwrap_or("Someone")
+                .to_string(),
+            created: h["created"].as_str().unwrap_or("").to_string(),
+            items: h["items"]
+                .as_array()
+                .map(|items| {
+                    items
+                        .iter()
+                        .map(|i| HistoryItem {
+                            field: i["field"].as_str().unwrap_or("").to_string(),
+                            from: i["fromString"].as_str().unwrap_or("").to_string(),
+                            to: i["toString"].as_str().unwrap_or("").to_string(),
+                            from_id: i["from"].as_str().unwrap_or("").to_string(),
+                            to_id: i["to"].as_str().unwrap_or("").to_string(),
+                        })
+                        .collect()
+                })
+                .unwrap_or_default(),
+        })
+        .collect()
+}
+
+fn related_from_json(issue: &serde_json::Value, relation: &str) -> Option<RelatedIssue> {
+    let key = issue["key"].as_str()?;
+    Some(RelatedIssue {
+        key: key.to_string(),
+        summary: issue["fields"]["summary"].as_str().unwrap_or("").to_string(),
+        status: issue["fields"]["status"]["name"].as_str().unwrap_or("").to_string(),
+        relation: relation.to_string(),
+        issue_type: issue["fields"]["issuetype"]["name"].as_str().unwrap_or("").to_string(),
+        assignee: issue["fields"]["assignee"]["displayName"]
+            .as_str()
+            .unwrap_or("")
+            .to_string(),
     })
 }
 
+fn related_from_issue(issue: JiraIssue, relation: &str) -> RelatedIssue {
+    RelatedIssue {
+        relation: relation.to_string(),
+        key: issue.key,
+        summary: issue.summary,
+        status: issue.status,
+        issue_type: issue.issue_type,
+        assignee: issue.assignee,
+    }
+}
+
+/// Children of `key` (subtasks, or issues in an epic) with their assignees,
+/// and its parent chain for the breadcrumb, outermost first. Children and
+/// the ancestors in `known_parents` come from one search; only ancestors
+/// above those take another search per level.
+async fn fetch_family(
+    client: &reqwest::Client,
+    config: &Config,
+    key: &str,
+    parent_key: Option<&str>,
+    known_parents: &std::collections::HashMap<String, String>,
+) -> Result<(Vec<RelatedIssue>, Vec<RelatedIssue>), String> {
+    const MAX_DEPTH: usize = 4;
+    // Ancestor keys, innermost first
+    let mut chain: Vec<String> = Vec::new();
+    let mut next = parent_key.map(str::to_string);
+    while let Some(k) = next.take() {
+        if chain.len() >= MAX_DEPTH || chain.contains(&k) || k == key {
+            break;
+        }
+        next = known_parents.get(&k).cloned();
+        chain.push(k);
+    }
+
+    let mut jql = format!("parent = {key}");
+    if !chain.is_empty() {
+        jql.push_str(&format!(" OR key in ({})", quoted_key_list(&chain)));
+    }
+    jql.push_str(" ORDER BY created ASC");
+    let mut children = Vec::new();
+    let mut fetched: std::collections::HashMap<String, JiraIssue> =
+        std::collections::HashMap::new();
+    for issue in search_issues(client, config, &jql).await? {
+        if issue.parent_key.as_deref() == Some(key) {
+            let relation = if issue.is_subtask { "Subtask" } else { "Child" };
+            children.push(related_from_issue(issue, relation));
+        } else {
+            fetched.insert(issue.key.clone(), issue);
+        }
+    }
+
+    // Follow the chain past what the loaded issues knew about
+    while chain.len() < MAX_DEPTH {
+        let above = match chain.last().and_then(|k| fetched.get(k)) {
+            Some(top) => top.parent_key.clone(),
+            None => None,
+        };
+        let above = match above {
+            Some(k) if !chain.contains(&k) && k != key => k,
+            _ => break,
+        };
+        let jql = format!("key in ({})", quoted_key_list(std::slice::from_ref(&above)));
+        for issue in search_issues(client, config, &jql).await? {
+            fetched.insert(issue.key.clone(), issue);
+        }
+        chain.push(above);
+    }
+
+    // Stop at the first ancestor that couldn't be fetched (e.g. no permission)
+    let mut ancestors = Vec::new();
+    for k in &chain {
+        match fetched.remove(k) {
+            Some(issue) => ancestors.push(related_from_issue(issue, "Parent")),
+            None => break,
+        }
+    }
+    ancestors.reverse();
+    Ok((children, ancestors))
+}
+
+fn collect_related(
+    key: &str,
+    fields: &serde_json::Value,
+    description: &str,
+    comments: &[Comment],
+) -> Vec<RelatedIssue> {
+    let mut related: Vec<RelatedIssue> = Vec::new();
+
+    if let Some(parent) = related_from_json(&fields["parent"], "Parent") {
+        related.push(parent);
+    }
+
+    if let Some(subtasks) = fields["subtasks"].as_array() {
+        related.extend(subtasks.iter().filter_map(|s| related_from_json(s, "Subtask")));
+    }
+
+    if let Some(links) = fields["issuelinks"].as_array() {
+        for link in links {
+            let (issue, relation) = if link["outwardIssue"].is_object() {
+                (&link["outwardIssue"], link["type"]["outward"].as_str())
+            } else {
+                (&link["inwardIssue"], link["type"]["inward"].as_str())
+            };
+            if let Some(r) = related_from_json(issue, relation.unwrap_or("relates to")) {
+                related.push(r);
+            }
+        }
+    }
+
+    // Keys mentioned in the description or comments
+    let texts = std::iter::once(description).chain(comments.iter().map(|c| c.body.as_str()));
+    for text in texts {
+        for mentioned in extract_issue_keys(text) {
+            if mentioned != key && !related.iter().any(|r| r.key == mentioned) {
+                related.push(RelatedIssue {
+                    key: mentioned,
+                    summary: String::new(),
+                    status: String::new(),
+                    relation: "Mentioned".to_string(),
+                    issue_type: String::new(),
+                    assignee: String::new(),
+                });
+            }
+        }
+    }
+
+    related
+}
+
+/// Find Jira issue keys (e.g. `ABC-123`) in free text, in order of appearance.
+pub fn extract_issue_keys(text: &str) -> Vec<String> {
+    let chars: Vec<char> = text.chars().collect();
+    let mut keys: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let at_boundary = i == 0 || !chars[i - 1].is_ascii_alphanumeric();
+        if !at_boundary || !chars[i].is_ascii_uppercase() {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && (chars[i].is_ascii_uppercase() || chars[i].is_ascii_digit()) {
+            i += 1;
+        }
+        let has_number = i + 1 < chars.len() && chars[i] == '-' && chars[i + 1].is_ascii_digit();
+        if !has_number || i - start < 2 {
+            continue;
+        }
+        i += 1;
+        while i < chars.len() && chars[i].is_ascii_digit() {
+            i += 1;
+        }
+        if i == chars.len() || !chars[i].is_ascii_alphanumeric() {
+            let candidate: String = chars[start..i].iter().collect();
+            if !keys.contains(&candidate) {
+                keys.push(candidate);
+            }
+        }
+    }
+    keys
+}
+
 // --- ADF (Atlassian Document Format) to plain text ---
 
 fn adf_to_text(value: &serde_json::Value) -> String {
@@ -629,6 +1219,108 @@ fn format_date(iso: &str) -> String {
     iso.get(..10).unwrap_or(iso).to_string()
 }
 
+/// `2024-03-01T14:05:09.000+0100` → `2024-03-01 14:05`
+pub fn format_timestamp(iso: &str) -> String {
+    iso.get(..16).unwrap_or(iso).replacen('T', " ", 1)
+}
+
+/// Seconds since the Unix epoch for a Jira timestamp such as
+/// `2024-03-01T14:05:09.000+0100` or `2024-03-01T13:05:09.000Z`.
+pub fn parse_timestamp(iso: &str) -> Option<i64> {
+    let num = |range: std::ops::Range<usize>| iso.get(range)?.parse::<i64>().ok();
+    let (year, month, day) = (num(0..4)?, num(5..7)?, num(8..10)?);
+    let (hour, min, sec) = if iso.len() >= 19 {
+        (num(11..13)?, num(14..16)?, num(17..19)?)
+    } else {
+        (0, 0, 0)
+    };
+
+    // Days since 1970-01-01 (Howard Hinnant's days_from_civil)
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let mp = (month + 9) % 12;
+    let doy = (153 * mp + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146097 + doe - 719468;
+
+    let tail = iso.get(19..).unwrap_or("");
+    let offset = match tail.find(['+', '-']) {
+        Some(i) => {
+            let sign = if tail[i..].starts_with('-') { -1 } else { 1 };
+            let digits: String = tail[i + 1..].chars().filter(char::is_ascii_digit).collect();
+            let h: i64 = digits.get(..2).and_then(|d| d.parse().ok()).unwrap_or(0);
+            let m: i64 = digits.get(2..4).and_then(|d| d.parse().ok()).unwrap_or(0);
+            sign * (h * 3600 + m * 60)
+        }
+        None => 0,
+    };
+    Some(days * 86400 + hour * 3600 + min * 60 + sec - offset)
+}
+
+/// `MM-DD` (UTC) of a Unix timestamp.
+pub fn format_day(ts: i64) -> String {
+    // Howard Hinnant's civil_from_days
+    let z = ts.div_euclid(86400) + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z - era * 146097;
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    format!("{month:02}-{day:02}")
+}
+
+pub fn now_timestamp() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs() as i64)
+        .unwrap_or(0)
+}
+
+/// Display text of a custom field value: option values, user names, numbers
+/// and lists of those. Empty for null or unknown shapes.
+fn custom_field_text(value: &serde_json::Value) -> String {
+    use serde_json::Value;
+    match value {
+        Value::String(s) => s.clone(),
+        Value::Number(n) => match n.as_f64() {
+            Some(f) if f.fract() == 0.0 => format!("{f:.0}"),
+            _ => n.to_string(),
+        },
+        Value::Bool(b) => b.to_string(),
+        Value::Array(items) => items
+            .iter()
+            .map(custom_field_text)
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Object(obj) => ["value", "displayName", "name"]
+            .iter()
+            .find_map(|k| obj.get(*k).and_then(Value::as_str))
+            .map(|s| match obj.get("child").map(custom_field_text) {
+                // Cascading selects nest the second level under "child"
+                Some(child) if !child.is_empty() => format!("{s} / {child}"),
+                _ => s.to_string(),
+            })
+            .unwrap_or_default(),
+        Value::Null => String::new(),
+    }
+}
+
+/// Compact age such as `now`, `12m`, `5h`, `3d` or `6w`.
+pub fn format_age(secs: i64) -> String {
+    let mins = secs.max(0) / 60;
+    match mins {
+        0 => "now".to_string(),
+        1..=59 => format!("{mins}m"),
+        60..=1439 => format!("{}h", mins / 60),
+        1440..=20159 => format!("{}d", mins / 1440),
+        _ => format!("{}w", mins / 10080),
+    }
+}
+
 // --- Comment CRUD ---
 
 /// Split a plain text segment into text nodes and inlineCard nodes for any URLs found.
@@ -867,28 +1559,24 @@ pub async fn update_summary(
     Ok(())
 }
 
-// --- Transitions ---
-
-pub struct Transition {
-    pub id: String,
-    pub name: String,
-    pub to_status: String,
-}
-
-pub async fn fetch_transitions(
+pub async fn assign_issue(
     config: &Config,
     issue_key: &str,
-) -> Result<Vec<Transition>, String> {
+    account_id: &str,
+) -> Result<(), String> {
     let url = format!(
-        "{}/rest/api/3/issue/{}/transitions",
+        "{}/rest/api/3/issue/{}/assignee",
         config.jira_url.trim_end_matches('/'),
         issue_key
     );
 
+    let payload = serde_json::json!({ "accountId": account_id });
+
     let client = reqwest::Client::new();
     let resp = client
-        .get(&url)
+        .put(&url)
         .basic_auth(&config.email, Some(&config.api_token))
+        .json(&payload)
         .send()
         .await
         .map_err(|e| format!("HTTP request failed: {e}"))?;
@@ -899,31 +1587,801 @@ pub async fn fetch_transitions(
         return Err(format!("Jira API error {status}: {body}"));
     }
 
-    let json: serde_json::Value = resp
-        .json()
-        .await
-        .map_err(|e| format!("Failed to parse: {e}"))?;
-
-    let transitions = json["transitions"]
-        .as_array()
-        .map(|arr| {
-            arr.iter()
-                .map(|t| Transition {
-                    id: t["id"].as_str().unwrap_or("").to_string(),
-                    name: t["name"].as_str().unwrap_or("").to_string(),
-                    to_status: t["to"]["name"].as_str().unwrap_or("").to_string(),
-                })
-                .collect()
-        })
-        .unwrap_or_default();
-
-    Ok(transitions)
+    Ok(())
 }
 
-pub async fn do_transition(
-    config: &Config,
+pub async fn add_label(config: &Config, issue_key: &str, label: &str) -> Result<(), String> {
+    let url = format!(
+        "{}/rest/api/3/issue/{}",
+        config.jira_url.trim_end_matches('/'),
+        issue_key
+    );
+
+    let payload = serde_json::json!({
+        "update": { "labels": [{ "add": label }] }
+    });
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .put(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .json(&payload)
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    Ok(())
+}
+
+// --- Watchers ---
+
+pub async fn fetch_watchers(config: &Config, issue_key: &str) -> Result<Vec<JiraUser>, String> {
+    let url = format!(
+        "{}/rest/api/3/issue/{}/watchers",
+        config.jira_url.trim_end_matches('/'),
+        issue_key
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    #[derive(Deserialize)]
+    struct WatchersResponse {
+        watchers: Vec<JiraUser>,
+    }
+
+    let watchers: WatchersResponse = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse: {e}"))?;
+    Ok(watchers.watchers)
+}
+
+pub async fn add_watcher(
+    config: &Config,
+    issue_key: &str,
+    account_id: &str,
+) -> Result<(), String> {
+    let url = format!(
+        "{}/rest/api/3/issue/{}/watchers",
+        config.jira_url.trim_end_matches('/'),
+        issue_key
+    );
+
+    // The endpoint takes the bare account ID as a JSON string
+    let client = reqwest::Client::new();
+    let resp = client
+        .post(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .json(&account_id)
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    Ok(())
+}
+
+pub async fn remove_watcher(
+    config: &Config,
+    issue_key: &str,
+    account_id: &str,
+) -> Result<(), String> {
+    let url = format!(
+        "{}/rest/api/3/issue/{}/watchers",
+        config.jira_url.trim_end_matches('/'),
+        issue_key
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .delete(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .query(&[("accountId", account_id)])
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    Ok(())
+}
+
+// --- Transitions ---
+
+#[derive(Clone)]
+pub struct Transition {
+    pub id: String,
+    pub name: String,
+    pub to_status: String,
+    pub to_status_id: String,
+    /// Fields the transition screen requires that Jira won't fill in by default
+    pub fields: Vec<TransitionField>,
+}
+
+#[derive(Clone)]
+pub struct TransitionField {
+    pub id: String,
+    pub name: String,
+    pub kind: TransitionFieldKind,
+    pub allowed_values: Vec<FieldOption>,
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum TransitionFieldKind {
+    /// Pick one of `allowed_values` (resolution, select lists, ...)
+    Option,
+    /// Multi-value field (components, versions, ...), filled with a single pick
+    MultiOption,
+    Text,
+    /// Text sent as an ADF document (description, paragraph custom fields)
+    RichText,
+    Number,
+    /// User pickers, cascading selects, ... — must be set in the browser
+    Unsupported,
+}
+
+#[derive(Clone)]
+pub struct FieldOption {
+    pub id: String,
+    pub name: String,
+}
+
+impl TransitionField {
+    /// Build the JSON value for this field from the form input, or explain what's missing.
+    pub fn to_json(&self, option: Option<usize>, text: &str) -> Result<serde_json::Value, String> {
+        let text = text.trim();
+        match self.kind {
+            TransitionFieldKind::Option | TransitionFieldKind::MultiOption => {
+                let opt = option
+                    .and_then(|i| self.allowed_values.get(i))
+                    .ok_or_else(|| format!("{} is required", self.name))?;
+                let value = serde_json::json!({ "id": opt.id });
+                if self.kind == TransitionFieldKind::MultiOption {
+                    Ok(serde_json::json!([value]))
+                } else {
+                    Ok(value)
+                }
+            }
+            TransitionFieldKind::Text | TransitionFieldKind::RichText if text.is_empty() => {
+                Err(format!("{} is required", self.name))
+            }
+            TransitionFieldKind::Text => Ok(serde_json::json!(text)),
+            TransitionFieldKind::RichText => Ok(text_to_adf(text, &[])),
+            TransitionFieldKind::Number => text
+                .parse::<f64>()
+                .map(|n| serde_json::json!(n))
+                .map_err(|_| format!("{} must be a number", self.name)),
+            TransitionFieldKind::Unsupported => {
+                Err(format!("{} can't be set here, use the browser", self.name))
+            }
+        }
+    }
+}
+
+fn parse_transition_field(id: &str, f: &serde_json::Value) -> Option<TransitionField> {
+    let required = f["required"].as_bool().unwrap_or(false);
+    let has_default = f["hasDefaultValue"].as_bool().unwrap_or(false);
+    if !required || has_default {
+        return None;
+    }
+
+    let allowed_values: Vec<FieldOption> = f["allowedValues"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|v| FieldOption {
+                    id: v["id"].as_str().unwrap_or("").to_string(),
+                    name: v["name"]
+                        .as_str()
+                        .or_else(|| v["value"].as_str())
+                        .unwrap_or("")
+                        .to_string(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    let schema = &f["schema"];
+    let custom = schema["custom"].as_str().unwrap_or("");
+    let kind = if !allowed_values.is_empty() {
+        if schema["type"] == "array" {
+            TransitionFieldKind::MultiOption
+        } else {
+            TransitionFieldKind::Option
+        }
+    } else {
+        match schema["type"].as_str().unwrap_or("") {
+            "string" if custom.ends_with(":textarea")
+                || matches!(schema["system"].as_str(), Some("description" | "environment")) =>
+            {
+                TransitionFieldKind::RichText
+            }
+            "string" | "date" | "datetime" => TransitionFieldKind::Text,
+            "number" => TransitionFieldKind::Number,
+            _ => TransitionFieldKind::Unsupported,
+        }
+    };
+
+    Some(TransitionField {
+        id: id.to_string(),
+        name: f["name"].as_str().unwrap_or(id).to_string(),
+        kind,
+        allowed_values,
+    })
+}
+
+pub async fn fetch_transitions(
+    config: &Config,
+    issue_key: &str,
+) -> Result<Vec<Transition>, String> {
+    let url = format!(
+        "{}/rest/api/3/issue/{}/transitions",
+        config.jira_url.trim_end_matches('/'),
+        issue_key
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .query(&[("expand", "transitions.fields")])
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse: {e}"))?;
+
+    let transitions = json["transitions"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|t| Transition {
+                    id: t["id"].as_str().unwrap_or("").to_string(),
+                    name: t["name"].as_str().unwrap_or("").to_string(),
+                    to_status: t["to"]["name"].as_str().unwrap_or("").to_string(),
+                    to_status_id: t["to"]["id"].as_str().unwrap_or("").to_string(),
+                    fields: t["fields"]
+                        .as_object()
+                        .map(|fields| {
+                            fields
+                                .iter()
+                                .filter_map(|(id, f)| parse_transition_field(id, f))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                })
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(transitions)
+}
+
+// --- Agile boards ---
+
+/// A column of an agile board and the statuses mapped to it.
+pub struct BoardColumn {
+    pub name: String,
+    pub status_ids: Vec<String>,
+}
+
+async fn fetch_board_configuration(
+    config: &Config,
+    board_id: u64,
+) -> Result<serde_json::Value, String> {
+    let url = format!(
+        "{}/rest/agile/1.0/board/{}/configuration",
+        config.jira_url.trim_end_matches('/'),
+        board_id
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    resp.json()
+        .await
+        .map_err(|e| format!("Failed to parse: {e}"))
+}
+
+pub async fn fetch_board_columns(
+    config: &Config,
+    board_id: u64,
+) -> Result<Vec<BoardColumn>, String> {
+    let json = fetch_board_configuration(config, board_id).await?;
+
+    let columns = json["columnConfig"]["columns"]
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .map(|c| BoardColumn {
+                    name: c["name"].as_str().unwrap_or("").to_string(),
+                    status_ids: c["statuses"]
+                        .as_array()
+                        .map(|statuses| {
+                            statuses
+                                .iter()
+                                .filter_map(|s| s["id"].as_str().map(str::to_string))
+                                .collect()
+                        })
+                        .unwrap_or_default(),
+                })
+                .filter(|c| !c.status_ids.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+
+    Ok(columns)
+}
+
+// --- Sprints ---
+
+#[derive(Clone)]
+pub struct Sprint {
+    pub id: u64,
+    pub name: String,
+    /// "active", "future" or "closed"
+    pub state: String,
+    pub start_date: String,
+    pub end_date: String,
+    /// When a closed sprint was completed; empty otherwise
+    pub complete_date: String,
+}
+
+/// Sprints of a board in the given states (comma separated), in board order.
+pub async fn fetch_sprints(
+    config: &Config,
+    board_id: u64,
+    state: &str,
+) -> Result<Vec<Sprint>, String> {
+    let url = format!(
+        "{}/rest/agile/1.0/board/{}/sprint",
+        config.jira_url.trim_end_matches('/'),
+        board_id
+    );
+
+    let client = reqwest::Client::new();
+    let mut sprints = Vec::new();
+    loop {
+        let start_at = sprints.len().to_string();
+        let resp = client
+            .get(&url)
+            .basic_auth(&config.email, Some(&config.api_token))
+            .query(&[("state", state), ("startAt", &start_at), ("maxResults", "50")])
+            .send()
+            .await
+            .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Jira API error {status}: {body}"));
+        }
+
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse: {e}"))?;
+
+        let values = json["values"].as_array().cloned().unwrap_or_default();
+        sprints.extend(values.iter().map(|s| Sprint {
+            id: s["id"].as_u64().unwrap_or(0),
+            name: s["name"].as_str().unwrap_or("").to_string(),
+            state: s["state"].as_str().unwrap_or("").to_string(),
+            start_date: s["startDate"].as_str().unwrap_or("").to_string(),
+            end_date: s["endDate"].as_str().unwrap_or("").to_string(),
+            complete_date: s["completeDate"].as_str().unwrap_or("").to_string(),
+        }));
+        if values.is_empty() || json["isLast"].as_bool().unwrap_or(true) {
+            return Ok(sprints);
+        }
+    }
+}
+
+/// Every issue in a sprint, whoever it's assigned to.
+pub async fn fetch_sprint_issues(config: &Config, sprint_id: u64) -> Result<Vec<JiraIssue>, String> {
+    let client = reqwest::Client::new();
+    search_all_issues(&client, config, &format!("sprint = {sprint_id}")).await
+}
+
+pub async fn move_to_sprint(config: &Config, sprint_id: u64, keys: &[String]) -> Result<(), String> {
+    let url = format!(
+        "{}/rest/agile/1.0/sprint/{}/issue",
+        config.jira_url.trim_end_matches('/'),
+        sprint_id
+    );
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .post(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .json(&serde_json::json!({ "issues": keys }))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    Ok(())
+}
+
+// --- Reports ---
+
+/// Remaining work of a sprint over time, in story points when a story
+/// points field is configured, otherwise in issues.
+pub struct Burndown {
+    pub unit: &'static str,
+    pub total: f64,
+    pub start: i64,
+    pub end: i64,
+    /// When each finished issue was done and how much it was worth, oldest first
+    pub completed: Vec<(i64, f64)>,
+}
+
+pub struct SprintVelocity {
+    pub name: String,
+    pub committed: f64,
+    pub completed: f64,
+}
+
+/// Field the board estimates with, e.g. a story points custom field.
+pub async fn fetch_estimation_field(config: &Config, board_id: u64) -> Result<Option<String>, String> {
+    let json = fetch_board_configuration(config, board_id).await?;
+    Ok(json["estimation"]["field"]["fieldId"]
+        .as_str()
+        .map(str::to_string))
+}
+
+/// Every issue of a search as raw JSON, optionally with their changelog,
+/// following `nextPageToken`.
+async fn search_raw(
+    client: &reqwest::Client,
+    config: &Config,
+    jql: &str,
+    fields: &str,
+    changelog: bool,
+) -> Result<Vec<serde_json::Value>, String> {
+    let url = format!(
+        "{}/rest/api/3/search/jql",
+        config.jira_url.trim_end_matches('/')
+    );
+
+    let mut issues = Vec::new();
+    let mut token: Option<String> = None;
+    loop {
+        let mut query = vec![("jql", jql), ("fields", fields), ("maxResults", "100")];
+        if changelog {
+            query.push(("expand", "changelog"));
+        }
+        if let Some(t) = &token {
+            query.push(("nextPageToken", t));
+        }
+        let resp = client
+            .get(&url)
+            .basic_auth(&config.email, Some(&config.api_token))
+            .query(&query)
+            .send()
+            .await
+            .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+        if !resp.status().is_success() {
+            let status = resp.status();
+            let body = resp.text().await.unwrap_or_default();
+            return Err(format!("Jira API error {status}: {body}"));
+        }
+
+        let json: serde_json::Value = resp
+            .json()
+            .await
+            .map_err(|e| format!("Failed to parse: {e}"))?;
+        issues.extend(json["issues"].as_array().cloned().unwrap_or_default());
+        match json["nextPageToken"].as_str() {
+            Some(t) => token = Some(t.to_string()),
+            None => return Ok(issues),
+        }
+    }
+}
+
+/// History of an issue fetched with `expand=changelog`, newest first. The
+/// embedded changelog is capped, so longer ones are paged separately.
+async fn issue_history(
+    client: &reqwest::Client,
+    config: &Config,
+    issue: &serde_json::Value,
+) -> Result<Vec<HistoryEntry>, String> {
+    let changelog = &issue["changelog"];
+    let embedded = changelog["histories"].as_array().map_or(0, Vec::len) as u64;
+    if changelog["total"].as_u64().unwrap_or(0) > embedded {
+        fetch_changelog(client, config, issue["key"].as_str().unwrap_or("")).await
+    } else {
+        Ok(parse_changelog(issue))
+    }
+}
+
+/// Id of the status an issue was in at `at`. `history` is newest first.
+fn status_at(history: &[HistoryEntry], at: i64, current_id: &str) -> String {
+    let mut status = current_id;
+    for h in history {
+        if parse_timestamp(&h.created).is_some_and(|t| t <= at) {
+            break;
+        }
+        if let Some(item) = h.items.iter().find(|i| i.field == "status") {
+            status = &item.from_id;
+        }
+    }
+    status.to_string()
+}
+
+fn issue_estimate(issue: &serde_json::Value, field: Option<&str>) -> f64 {
+    match field {
+        Some(f) => issue["fields"][f].as_f64().unwrap_or(0.0),
+        None => 1.0,
+    }
+}
+
+fn is_done(issue: &serde_json::Value) -> bool {
+    issue["fields"]["status"]["statusCategory"]["key"].as_str() == Some("done")
+}
+
+pub async fn fetch_burndown(config: &Config, sprint: &Sprint) -> Result<Burndown, String> {
+    let field = config.story_points_field.clone();
+    let fields = match &field {
+        Some(f) => format!("status,{f}"),
+        None => "status".to_string(),
+    };
+    let client = reqwest::Client::new();
+    let jql = format!("sprint = {}", sprint.id);
+    let issues = search_raw(&client, config, &jql, &fields, true).await?;
+
+    let start = parse_timestamp(&sprint.start_date).unwrap_or_else(now_timestamp);
+    let end = parse_timestamp(&sprint.end_date).unwrap_or(start);
+    let mut total = 0.0;
+    let mut completed = Vec::new();
+    for issue in &issues {
+        let estimate = issue_estimate(issue, field.as_deref());
+        total += estimate;
+        if !is_done(issue) {
+            continue;
+        }
+        // The last status change is the one that finished it
+        let done_at = issue_history(&client, config, issue)
+            .await?
+            .iter()
+            .find(|h| h.items.iter().any(|i| i.field == "status"))
+            .and_then(|h| parse_timestamp(&h.created))
+            .unwrap_or(start)
+            .max(start);
+        completed.push((done_at, estimate));
+    }
+    completed.sort_by_key(|(at, _)| *at);
+
+    Ok(Burndown {
+        unit: if field.is_some() { "points" } else { "issues" },
+        total,
+        start,
+        end,
+        completed,
+    })
+}
+
+/// Work in and completed by each of the last `count` closed sprints, oldest
+/// first. "Committed" is what the sprint held when it closed; "completed" is
+/// what was in a done status at that moment, whatever happened since.
+/// `categories` maps status id to category key.
+pub async fn fetch_velocity(
+    config: &Config,
+    board_id: u64,
+    count: usize,
+    categories: &std::collections::HashMap<String, String>,
+) -> Result<Vec<SprintVelocity>, String> {
+    let field = config.story_points_field.clone();
+    let fields = match &field {
+        Some(f) => format!("status,{f}"),
+        None => "status".to_string(),
+    };
+    let mut closed = fetch_sprints(config, board_id, "closed").await?;
+    let closed_at = |s: &Sprint| {
+        parse_timestamp(&s.complete_date).or_else(|| parse_timestamp(&s.end_date))
+    };
+    closed.sort_by_cached_key(closed_at);
+    let recent = &closed[closed.len().saturating_sub(count)..];
+
+    let client = reqwest::Client::new();
+    let mut velocity = Vec::with_capacity(recent.len());
+    for sprint in recent {
+        let jql = format!("sprint = {}", sprint.id);
+        let issues = search_raw(&client, config, &jql, &fields, true).await?;
+        let close = closed_at(sprint).unwrap_or_else(now_timestamp);
+        let mut committed = 0.0;
+        let mut completed = 0.0;
+        for issue in &issues {
+            let estimate = issue_estimate(issue, field.as_deref());
+            committed += estimate;
+            let current = issue["fields"]["status"]["id"].as_str().unwrap_or("");
+            let history = issue_history(&client, config, issue).await?;
+            let status = status_at(&history, close, current);
+            if categories.get(&status).map(String::as_str) == Some("done") {
+                completed += estimate;
+            }
+        }
+        velocity.push(SprintVelocity {
+            name: sprint.name.clone(),
+            committed,
+            completed,
+        });
+    }
+    Ok(velocity)
+}
+
+// --- Flow metrics ---
+
+/// A finished issue with the changelog needed for flow metrics.
+pub struct FlowIssue {
+    pub key: String,
+    pub summary: String,
+    pub created: String,
+    pub status: String,
+    pub status_id: String,
+    /// Newest first
+    pub history: Vec<HistoryEntry>,
+}
+
+/// Status id → category key ("new", "indeterminate" or "done"). Keyed by
+/// id since different workflows can use the same status name.
+pub async fn fetch_status_categories(
+    config: &Config,
+) -> Result<std::collections::HashMap<String, String>, String> {
+    let url = format!("{}/rest/api/3/status", config.jira_url.trim_end_matches('/'));
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse: {e}"))?;
+
+    Ok(json
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter_map(|s| {
+                    let id = s["id"].as_str()?;
+                    let category = s["statusCategory"]["key"].as_str()?;
+                    Some((id.to_string(), category.to_string()))
+                })
+                .collect()
+        })
+        .unwrap_or_default())
+}
+
+/// Custom fields of the instance as (id, name), sorted by name.
+pub async fn fetch_custom_fields(config: &Config) -> Result<Vec<(String, String)>, String> {
+    let url = format!("{}/rest/api/3/field", config.jira_url.trim_end_matches('/'));
+
+    let client = reqwest::Client::new();
+    let resp = client
+        .get(&url)
+        .basic_auth(&config.email, Some(&config.api_token))
+        .send()
+        .await
+        .map_err(|e| format!("HTTP request failed: {e}"))?;
+
+    if !resp.status().is_success() {
+        let status = resp.status();
+        let body = resp.text().await.unwrap_or_default();
+        return Err(format!("Jira API error {status}: {body}"));
+    }
+
+    let json: serde_json::Value = resp
+        .json()
+        .await
+        .map_err(|e| format!("Failed to parse: {e}"))?;
+
+    let mut fields: Vec<(String, String)> = json
+        .as_array()
+        .map(|arr| {
+            arr.iter()
+                .filter(|f| f["custom"].as_bool() == Some(true))
+                .filter_map(|f| Some((f["id"].as_str()?.to_string(), f["name"].as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default();
+    fields.sort_by_key(|(_, name)| name.to_lowercase());
+    Ok(fields)
+}
+
+/// My issues that reached a done status in the last `days` days.
+pub async fn fetch_finished_issues(config: &Config, days: i64) -> Result<Vec<FlowIssue>, String> {
+    let jql = format!(
+        "assignee = currentUser() AND statusCategory = Done AND statusCategoryChangedDate >= -{days}d ORDER BY statusCategoryChangedDate DESC"
+    );
+    let client = reqwest::Client::new();
+    let issues = search_raw(&client, config, &jql, "summary,status,created", true).await?;
+    let mut finished = Vec::with_capacity(issues.len());
+    for issue in &issues {
+        let fields = &issue["fields"];
+        finished.push(FlowIssue {
+            key: issue["key"].as_str().unwrap_or("").to_string(),
+            summary: fields["summary"].as_str().unwrap_or("").to_string(),
+            created: fields["created"].as_str().unwrap_or("").to_string(),
+            status: fields["status"]["name"].as_str().unwrap_or("").to_string(),
+            status_id: fields["status"]["id"].as_str().unwrap_or("").to_string(),
+            history: issue_history(&client, config, issue).await?,
+        });
+    }
+    Ok(finished)
+}
+
+/// Run a transition, filling its screen `fields` (field id → JSON value) and
+/// adding `comment` when it isn't empty.
+pub async fn do_transition(
+    config: &Config,
     issue_key: &str,
     transition_id: &str,
+    fields: &serde_json::Map<String, serde_json::Value>,
+    comment: &str,
 ) -> Result<(), String> {
     let url = format!(
         "{}/rest/api/3/issue/{}/transitions",
@@ -931,9 +2389,18 @@ pub async fn do_transition(
         issue_key
     );
 
-    let payload = serde_json::json!({
+    let mut payload = serde_json::json!({
         "transition": { "id": transition_id }
     });
+    if !fields.is_empty() {
+        payload["fields"] = serde_json::Value::Object(fields.clone());
+    }
+    let comment = comment.trim();
+    if !comment.is_empty() {
+        payload["update"] = serde_json::json!({
+            "comment": [{ "add": { "body": text_to_adf(comment, &[]) } }]
+        });
+    }
 
     let client = reqwest::Client::new();
     let resp = client
@@ -955,31 +2422,38 @@ pub async fn do_transition(
 
 // --- Tree building ---
 
+/// Order issues depth-first so every issue follows its parent. Issues whose
+/// parent isn't in the list become roots.
 fn build_tree(issues: Vec<JiraIssue>) -> Vec<JiraIssue> {
-    use std::collections::HashMap;
+    use std::collections::{HashMap, HashSet};
 
-    let mut parent_issues: Vec<JiraIssue> = Vec::new();
+    let keys: HashSet<String> = issues.iter().map(|i| i.key.clone()).collect();
+    let mut roots: Vec<JiraIssue> = Vec::new();
     let mut children: HashMap<String, Vec<JiraIssue>> = HashMap::new();
 
-    for issue in issues {
-        if let Some(ref pk) = issue.parent_key {
-            children.entry(pk.clone()).or_default().push(issue);
-        } else {
-            parent_issues.push(issue);
+    for mut issue in issues {
+        match issue.parent_key.clone() {
+            Some(pk) if keys.contains(&pk) && pk != issue.key => {
+                children.entry(pk).or_default().push(issue);
+            }
+            _ => {
+                issue.parent_key = None;
+                issue.is_subtask = false;
+                roots.push(issue);
+            }
         }
     }
 
     let mut result = Vec::new();
-    for parent in parent_issues {
-        let key = parent.key.clone();
-        result.push(parent);
-        if let Some(kids) = children.remove(&key) {
-            for child in kids {
-                result.push(child);
-            }
+    let mut stack: Vec<JiraIssue> = roots.into_iter().rev().collect();
+    while let Some(issue) = stack.pop() {
+        if let Some(kids) = children.remove(&issue.key) {
+            stack.extend(kids.into_iter().rev());
         }
+        result.push(issue);
     }
 
+    // Parent cycles never reach a root; keep those issues as roots
     for (_key, orphans) in children {
         for mut orphan in orphans {
             orphan.parent_key = None;
@@ -990,3 +2464,61 @@ fn build_tree(issues: Vec<JiraIssue>) -> Vec<JiraIssue> {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn text_search_escapes_lucene_operators() {
+        assert_eq!(text_search_jql("login page"), r#"text ~ "login page" ORDER BY updated DESC"#);
+        assert_eq!(
+            text_search_jql("-foo bar* a?"),
+            r#"text ~ "\\-foo bar\\* a\\?" ORDER BY updated DESC"#
+        );
+        assert_eq!(
+            text_search_jql(r#"say "hi" \ ~x"#),
+            r#"text ~ "say \\\"hi\\\" \\\\ \\~x" ORDER BY updated DESC"#
+        );
+    }
+
+    #[test]
+    fn parse_timestamp_applies_offsets() {
+        let utc = parse_timestamp("2024-03-01T13:05:09.000Z");
+        assert_eq!(utc, Some(1709298309));
+        assert_eq!(parse_timestamp("2024-03-01T14:05:09.000+0100"), utc);
+        assert_eq!(parse_timestamp("2024-03-01T08:35:09.000-0430"), utc);
+        assert_eq!(parse_timestamp("2024-03-01T13:05:09.000+0000"), utc);
+    }
+
+    #[test]
+    fn parse_timestamp_crosses_day_and_year_with_offset() {
+        // Midnight in UTC+05:30 is still the previous day in UTC
+        assert_eq!(parse_timestamp("2024-01-01T00:00:00.000+0530"), Some(1704047400));
+        assert_eq!(format_day(1704047400), "12-31");
+    }
+
+    #[test]
+    fn parse_timestamp_handles_leap_years() {
+        assert_eq!(parse_timestamp("2024-02-29T00:00:00.000Z"), Some(1709164800));
+        assert_eq!(parse_timestamp("2024-03-01"), Some(1709251200));
+        assert_eq!(parse_timestamp("2000-02-29T00:00:00.000Z"), Some(951782400));
+        // 1900 is not a leap year
+        assert_eq!(parse_timestamp("1900-03-01"), Some(-2203891200));
+    }
+
+    #[test]
+    fn parse_timestamp_rejects_garbage() {
+        assert_eq!(parse_timestamp(""), None);
+        assert_eq!(parse_timestamp("yesterday"), None);
+    }
+
+    #[test]
+    fn format_day_round_trips_leap_days() {
+        assert_eq!(format_day(1709164800), "02-29");
+        assert_eq!(format_day(1709251200), "03-01");
+        assert_eq!(format_day(951782400), "02-29");
+        assert_eq!(format_day(-2203891200), "03-01");
+        assert_eq!(format_day(-1), "12-31");
+    }
+}
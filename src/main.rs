@@ -1,8 +1,10 @@
 mod app;
 mod config;
+mod flow;
 mod github;
 mod jira;
 mod notes;
+mod query;
 mod ui;
 
 use std::io;
@@ -17,11 +19,11 @@ use crossterm::terminal::{
     disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
 };
 use crossterm::ExecutableCommand;
-use ratatui::backend::CrosstermBackend;
+use ratatui::backend::{Backend, CrosstermBackend};
 use ratatui::Terminal;
 
-use app::{App, Mode};
-use config::Config;
+use app::{App, DetailTab, Mode};
+use config::{Config, CustomField};
 
 fn char_byte_pos(s: &str, char_pos: usize) -> usize {
     s.char_indices()
@@ -64,7 +66,103 @@ fn prompt(label: &str, default: &str) -> String {
     if input.is_empty() { default.to_string() } else { input }
 }
 
-fn run_setup() {
+/// Resolve `Name`, `Name=Jira field name` or `Name=customfield_10042` entries,
+/// looking names up in the instance's custom fields.
+async fn resolve_custom_fields(config: &Config, entries: &[&str]) -> Vec<CustomField> {
+    let needs_lookup = entries
+        .iter()
+        .any(|e| !e.split_once('=').is_some_and(|(_, t)| t.trim().starts_with("customfield_")));
+    let available = if needs_lookup {
+        match jira::fetch_custom_fields(config).await {
+            Ok(fields) => fields,
+            Err(e) => {
+                println!("  Could not list custom fields: {e}");
+                Vec::new()
+            }
+        }
+    } else {
+        Vec::new()
+    };
+
+    let mut resolved = Vec::new();
+    for entry in entries {
+        let (name, target) = match entry.split_once('=') {
+            Some((name, target)) => (name.trim(), target.trim()),
+            None => (*entry, *entry),
+        };
+        let id = if target.starts_with("customfield_") {
+            Some(target.to_string())
+        } else {
+            available
+                .iter()
+                .find(|(_, n)| n.eq_ignore_ascii_case(target))
+                .map(|(id, _)| id.clone())
+        };
+        match id {
+            Some(id) => {
+                println!("  {name} → {id}");
+                resolved.push(CustomField { name: name.to_string(), id });
+            }
+            None => {
+                let lower = target.to_lowercase();
+                let similar: Vec<&str> = available
+                    .iter()
+                    .map(|(_, n)| n.as_str())
+                    .filter(|n| n.to_lowercase().contains(&lower))
+                    .take(5)
+                    .collect();
+                if similar.is_empty() {
+                    println!("  No custom field named \"{target}\", skipped");
+                } else {
+                    println!(
+                        "  No custom field named \"{target}\", skipped (similar: {})",
+                        similar.join(", ")
+                    );
+                }
+            }
+        }
+    }
+    resolved
+}
+
+/// Ask for the story points field, suggesting the board's estimation field
+/// when there is no existing choice.
+async fn setup_story_points_field(config: &Config, existing: Option<&str>) -> Option<String> {
+    let mut default = existing.map(str::to_string);
+    if let (None, Some(board_id)) = (&default, config.board_id) {
+        match jira::fetch_estimation_field(config, board_id).await {
+            Ok(Some(field)) if field.starts_with("customfield_") => default = Some(field),
+            Ok(_) => {}
+            Err(e) => println!("  Could not read the board's estimation field: {e}"),
+        }
+    }
+    let input = prompt(
+        "Story points field, as Jira name or customfield_ id (optional)",
+        default.as_deref().unwrap_or(""),
+    );
+    if input.is_empty() {
+        return None;
+    }
+    let entry = format!("Story points={input}");
+    resolve_custom_fields(config, &[entry.as_str()])
+        .await
+        .into_iter()
+        .next()
+        .map(|f| f.id)
+}
+
+/// Fetch PRs when the detail view has landed on the PRs tab of an issue
+/// whose PRs aren't loaded yet.
+async fn load_stale_prs<B: Backend>(terminal: &mut Terminal<B>, app: &mut App) -> io::Result<()> {
+    if app.prs_stale() {
+        app.set_detail_status("Fetching PRs...");
+        terminal.draw(|f| ui::draw(f, app))?;
+        app.load_prs().await;
+    }
+    Ok(())
+}
+
+async fn run_setup() {
     let existing = Config::load().ok();
 
     if let Some(ref config) = existing {
@@ -95,25 +193,81 @@ fn run_setup() {
     let jira_url = prompt("Jira URL", existing.as_ref().map_or("", |c| &c.jira_url));
     let email = prompt("Email", existing.as_ref().map_or("", |c| &c.email));
     let api_token = prompt("API token", existing.as_ref().map_or("", |c| &c.api_token));
+    let existing_board = existing
+        .as_ref()
+        .and_then(|c| c.board_id)
+        .map(|id| id.to_string())
+        .unwrap_or_default();
+    let board_id = prompt("Agile board ID (optional)", &existing_board).parse().ok();
+    let existing_custom = existing
+        .as_ref()
+        .map(|c| {
+            c.custom_fields
+                .iter()
+                .map(|f| format!("{}={}", f.name, f.id))
+                .collect::<Vec<_>>()
+                .join(", ")
+        })
+        .unwrap_or_default();
+    let custom_input = prompt(
+        "Custom fields, comma separated, as Name or Name=Jira name (optional)",
+        &existing_custom,
+    );
 
     let sort_order = existing.as_ref().and_then(|c| c.sort_order.clone());
+    let manual_new_position = existing.as_ref().and_then(|c| c.manual_new_position.clone());
+    let group_by = existing.as_ref().and_then(|c| c.group_by.clone());
     let hidden_columns = existing.as_ref().map(|c| c.hidden_columns.clone()).unwrap_or_default();
+    let columns = existing.as_ref().and_then(|c| c.columns.clone());
+    let column_widths = existing.as_ref().map(|c| c.column_widths.clone()).unwrap_or_default();
     let github_repo = existing.as_ref().and_then(|c| c.github_repo.clone());
     let comfortable_spacing = existing.as_ref().map(|c| c.comfortable_spacing).unwrap_or(false);
+    let search_natural_order = existing.as_ref().is_some_and(|c| c.search_natural_order);
+    let search_notes = existing.as_ref().is_some_and(|c| c.search_notes);
+    let velocity_sprints = existing.as_ref().map(|c| c.velocity_sprints).unwrap_or(6);
+    let existing_points = existing.as_ref().and_then(|c| c.story_points_field.clone());
     let status_filters = existing
         .map(|c| c.status_filters)
         .unwrap_or_else(config::default_status_filters);
 
-    let config = Config {
+    let mut config = Config {
         jira_url,
         email,
         api_token,
         status_filters,
         sort_order,
+        manual_new_position,
+        group_by,
         hidden_columns,
+        columns,
+        column_widths,
         github_repo,
         comfortable_spacing,
+        search_natural_order,
+        search_notes,
+        board_id,
+        velocity_sprints,
+        story_points_field: None,
+        custom_fields: Vec::new(),
     };
+    config.story_points_field =
+        setup_story_points_field(&config, existing_points.as_deref()).await;
+    let entries: Vec<&str> = custom_input
+        .split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .filter(|entry| {
+            let name = entry.split_once('=').map_or(*entry, |(name, _)| name.trim());
+            let builtin = app::is_builtin_name(name);
+            if builtin {
+                println!("  \"{name}\" is taken by a built-in column, sort key or search field, skipped");
+            }
+            !builtin
+        })
+        .collect();
+    if !entries.is_empty() {
+        config.custom_fields = resolve_custom_fields(&config, &entries).await;
+    }
     config.save();
 
     println!("\nConfig saved to {}", config::config_dir().join("config.json").display());
@@ -124,7 +278,7 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = std::env::args().collect();
     match args.get(1).map(|s| s.as_str()) {
         Some("setup") => {
-            run_setup();
+            run_setup().await;
             return Ok(());
         }
         Some("--version" | "-v") => {
@@ -177,9 +331,43 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             _ => {}
                         },
                         Mode::Normal => match key.code {
+                            KeyCode::Esc if app.has_marks() => app.clear_marks(),
                             KeyCode::Char('q') | KeyCode::Esc => app.confirm_quit(),
+                            KeyCode::Char('b') => {
+                                app.set_status("Loading board...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.toggle_board().await;
+                            }
+                            KeyCode::Up | KeyCode::Char('k') if app.board_view => app.board_move_up(),
+                            KeyCode::Down | KeyCode::Char('j') if app.board_view => app.board_move_down(),
+                            KeyCode::Left | KeyCode::Right | KeyCode::Char('<') | KeyCode::Char('>')
+                                if app.board_view =>
+                            {
+                                let forward = matches!(key.code, KeyCode::Right | KeyCode::Char('>'));
+                                let move_card = key.modifiers.contains(KeyModifiers::SHIFT)
+                                    || matches!(key.code, KeyCode::Char('<') | KeyCode::Char('>'));
+                                if move_card {
+                                    if let Some(row) = app.rows.get(app.selected) {
+                                        let key = row.issue.key.clone();
+                                        app.set_status(format!("Moving {key}..."));
+                                        terminal.draw(|f| ui::draw(f, &app))?;
+                                    }
+                                    app.board_move_card(forward).await;
+                                } else {
+                                    app.board_move_lane(forward);
+                                }
+                            }
+                            KeyCode::Char(' ') => app.toggle_mark(),
+                            KeyCode::Char('V') => app.toggle_visual(),
+                            KeyCode::Char('a') => app.start_assign(),
+                            KeyCode::Char('L') => app.start_adding_label(),
+                            KeyCode::Char('E') => app.export_targets(),
                             KeyCode::Up | KeyCode::Char('k') => app.move_up(),
                             KeyCode::Down | KeyCode::Char('j') => app.move_down(),
+                            KeyCode::Left => app.collapse_selected(),
+                            KeyCode::Right => app.expand_selected(),
+                            KeyCode::Char('-') => app.collapse_all(),
+                            KeyCode::Char('+') | KeyCode::Char('=') => app.expand_all(),
                             KeyCode::Enter => {
                                 if let Some(row) = app.rows.get(app.selected) {
                                     let key = row.issue.key.clone();
@@ -196,12 +384,34 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             KeyCode::Char('y') => app.copy_key_to_clipboard(),
                             KeyCode::Char('f') => app.open_filter_editor(),
                             KeyCode::Char('/') => app.start_search(),
+                            KeyCode::Char('g') => app.start_quick_open(),
+                            KeyCode::Char('P') => app.toggle_pin_selected().await,
+                            KeyCode::Char('t') => {
+                                app.set_status("Loading transitions...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_list_transition_picker().await;
+                            }
                             KeyCode::Char('p') => {
                                 app.set_status("Fetching issues...");
                                 terminal.draw(|f| ui::draw(f, &app))?;
                                 app.toggle_show_all_parents().await;
                             }
                             KeyCode::Char('o') => app.open_sort_picker(),
+                            KeyCode::Char('K') if !app.board_view => app.move_manual(true),
+                            KeyCode::Char('J') if !app.board_view => app.move_manual(false),
+                            KeyCode::Char('G') => app.open_group_picker(),
+                            KeyCode::Char('S') => app.toggle_sprint_only(),
+                            KeyCode::Char('M') => app.open_sprint_picker(),
+                            KeyCode::Char('F') => {
+                                app.set_status("Loading stats...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_stats().await;
+                            }
+                            KeyCode::Char('R') => {
+                                app.set_status("Loading reports...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_reports().await;
+                            }
                             KeyCode::Char('c') => app.open_column_picker(),
                             KeyCode::Char('r') => {
                                 app.set_status("Fetching issues...");
@@ -217,11 +427,89 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             KeyCode::Char('?') => app.show_legend = !app.show_legend,
                             _ => {}
                         },
+                        Mode::QuickOpen => match key.code {
+                            KeyCode::Esc => app.cancel_quick_open(),
+                            KeyCode::Enter => {
+                                app.set_status("Loading...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.confirm_quick_open().await;
+                            }
+                            KeyCode::Up => app.quick_open_move_up(),
+                            KeyCode::Down | KeyCode::Tab => app.quick_open_move_down(),
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.quick_open_input.chars().count() => app.cursor_pos += 1,
+                            KeyCode::Home => app.cursor_pos = 0,
+                            KeyCode::End => {
+                                app.cursor_pos = app.quick_open_input.chars().count()
+                            }
+                            KeyCode::Backspace => {
+                                input_backspace(&mut app.quick_open_input, &mut app.cursor_pos);
+                                app.quick_open_input_changed();
+                            }
+                            KeyCode::Delete => {
+                                input_delete(&mut app.quick_open_input, &mut app.cursor_pos);
+                                app.quick_open_input_changed();
+                            }
+                            KeyCode::Char(c) => {
+                                input_insert(&mut app.quick_open_input, &mut app.cursor_pos, c);
+                                app.quick_open_input_changed();
+                            }
+                            _ => {}
+                        },
+                        Mode::AssignPicker => match key.code {
+                            KeyCode::Esc => app.cancel_assign(),
+                            KeyCode::Up => app.assign_move_up(),
+                            KeyCode::Down | KeyCode::Tab => app.assign_move_down(),
+                            KeyCode::Enter => {
+                                app.set_status("Assigning...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.assign_selected_user().await;
+                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.assign_input.chars().count() => app.cursor_pos += 1,
+                            KeyCode::Backspace => {
+                                input_backspace(&mut app.assign_input, &mut app.cursor_pos);
+                                app.fetch_assign_candidates().await;
+                            }
+                            KeyCode::Char(c) => {
+                                input_insert(&mut app.assign_input, &mut app.cursor_pos, c);
+                                app.fetch_assign_candidates().await;
+                            }
+                            _ => {}
+                        },
+                        Mode::AddingLabel => match key.code {
+                            KeyCode::Esc => app.cancel_adding_label(),
+                            KeyCode::Enter => {
+                                app.set_status("Adding label...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.add_label_to_targets().await;
+                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.label_input.chars().count() => app.cursor_pos += 1,
+                            KeyCode::Home => app.cursor_pos = 0,
+                            KeyCode::End => app.cursor_pos = app.label_input.chars().count(),
+                            KeyCode::Backspace => input_backspace(&mut app.label_input, &mut app.cursor_pos),
+                            KeyCode::Delete => input_delete(&mut app.label_input, &mut app.cursor_pos),
+                            KeyCode::Char(c) => input_insert(&mut app.label_input, &mut app.cursor_pos, c),
+                            _ => {}
+                        },
                         Mode::Searching => match key.code {
                             KeyCode::Esc => app.clear_search(),
                             KeyCode::Enter => app.confirm_search(),
                             KeyCode::Up | KeyCode::Char('\x1b') => app.move_up(),
                             KeyCode::Down => app.move_down(),
+                            KeyCode::Tab => app.complete_search(),
+                            KeyCode::Char('r') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.toggle_search_ranking()
+                            }
+                            KeyCode::Char('n') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.toggle_search_notes()
+                            }
+                            KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                                app.set_status("Searching Jira...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.run_deep_search().await;
+                            }
                             KeyCode::Backspace => {
                                 app.search_input.pop();
                                 app.apply_search_filter();
@@ -238,10 +526,31 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             _ => {}
                         },
                         Mode::TicketDetail => match key.code {
+                            KeyCode::Esc if !app.detail_search_input.is_empty() => {
+                                app.clear_detail_search()
+                            }
                             KeyCode::Esc => app.close_detail(),
+                            KeyCode::Enter if app.detail_item_count() > 0 => {
+                                app.set_detail_status("Loading...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.detail_open_selected_item().await;
+                            }
                             KeyCode::Enter => app.detail_open_in_browser(),
+                            KeyCode::Up | KeyCode::Char('k') if app.detail_tab.is_list() => {
+                                app.detail_item_up()
+                            }
+                            KeyCode::Down | KeyCode::Char('j') if app.detail_tab.is_list() => {
+                                app.detail_item_down()
+                            }
                             KeyCode::Up | KeyCode::Char('k') => app.detail_scroll_up(),
                             KeyCode::Down | KeyCode::Char('j') => app.detail_scroll_down(),
+                            KeyCode::Char('/') => app.start_detail_search(),
+                            KeyCode::Char('n') if !app.detail_search_input.is_empty() => {
+                                app.detail_search_next()
+                            }
+                            KeyCode::Char('N' | 'p') if !app.detail_search_input.is_empty() => {
+                                app.detail_search_prev()
+                            }
                             KeyCode::Char('n') => app.detail_next_comment(),
                             KeyCode::Char('p') => app.detail_prev_comment(),
                             KeyCode::Char('y') => app.copy_ticket_to_clipboard(),
@@ -255,35 +564,168 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 app.open_transition_picker().await;
                             }
                             KeyCode::Char('s') => app.start_editing_summary(),
-                            KeyCode::Char('g') => {
-                                app.set_detail_status("Fetching PRs...");
+                            KeyCode::Char('o') => app.open_related_picker(),
+                            KeyCode::Char('u') => {
+                                app.set_detail_status("Loading parent...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.detail_open_parent().await;
+                            }
+                            KeyCode::Char('P') => app.toggle_pin_detail().await,
+                            KeyCode::Char('w') => {
+                                app.set_detail_status("Updating watch state...");
                                 terminal.draw(|f| ui::draw(f, &app))?;
-                                app.open_pr_list().await;
+                                app.toggle_watch().await;
+                            }
+                            KeyCode::Char('W') => {
+                                app.set_detail_status("Loading watchers...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_watchers().await;
+                            }
+                            KeyCode::Backspace | KeyCode::Char('[') => {
+                                app.detail_go_back();
+                                load_stale_prs(&mut terminal, &mut app).await?;
+                            }
+                            KeyCode::Char(']') => {
+                                app.detail_go_forward();
+                                load_stale_prs(&mut terminal, &mut app).await?;
+                            }
+                            KeyCode::Tab => {
+                                app.detail_next_tab();
+                                load_stale_prs(&mut terminal, &mut app).await?;
+                            }
+                            KeyCode::BackTab => {
+                                app.detail_prev_tab();
+                                load_stale_prs(&mut terminal, &mut app).await?;
+                            }
+                            KeyCode::Char('g') => {
+                                app.set_detail_tab(DetailTab::PRs);
+                                load_stale_prs(&mut terminal, &mut app).await?;
+                            }
+                            KeyCode::Char(c @ '1'..='6') => {
+                                app.set_detail_tab(DetailTab::ALL[c as usize - '1' as usize]);
+                                load_stale_prs(&mut terminal, &mut app).await?;
                             }
                             KeyCode::Char('?') => app.show_legend = !app.show_legend,
                             _ => {}
                         },
-                        Mode::DetailPRList => match key.code {
-                            KeyCode::Esc | KeyCode::Char('q') => app.close_pr_list(),
-                            KeyCode::Up | KeyCode::Char('k') => app.pr_list_move_up(),
-                            KeyCode::Down | KeyCode::Char('j') => app.pr_list_move_down(),
-                            KeyCode::Enter => app.open_selected_pr(),
+                        Mode::DetailSearching => match key.code {
+                            KeyCode::Esc => app.clear_detail_search(),
+                            KeyCode::Enter => app.confirm_detail_search(),
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.detail_search_input.chars().count() => app.cursor_pos += 1,
+                            KeyCode::Backspace => {
+                                input_backspace(&mut app.detail_search_input, &mut app.cursor_pos);
+                                app.detail_search_changed();
+                            }
+                            KeyCode::Char(c) => {
+                                input_insert(&mut app.detail_search_input, &mut app.cursor_pos, c);
+                                app.detail_search_changed();
+                            }
+                            _ => {}
+                        },
+                        Mode::DetailWatchers => match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => app.close_watchers(),
+                            KeyCode::Up | KeyCode::Char('k') => app.watchers_move_up(),
+                            KeyCode::Down | KeyCode::Char('j') => app.watchers_move_down(),
+                            KeyCode::Char('a') => app.start_adding_watcher(),
+                            KeyCode::Char('x') => {
+                                app.set_detail_status("Removing watcher...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.remove_selected_watcher().await;
+                            }
+                            KeyCode::Char('w') => {
+                                app.set_detail_status("Updating watch state...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.toggle_watch().await;
+                            }
+                            _ => {}
+                        },
+                        Mode::DetailAddingWatcher => match key.code {
+                            KeyCode::Esc => app.cancel_adding_watcher(),
+                            KeyCode::Up => app.watcher_candidate_move_up(),
+                            KeyCode::Down | KeyCode::Tab => app.watcher_candidate_move_down(),
+                            KeyCode::Enter => {
+                                app.set_detail_status("Adding watcher...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.add_selected_watcher().await;
+                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.watcher_input.chars().count() => app.cursor_pos += 1,
+                            KeyCode::Backspace => {
+                                input_backspace(&mut app.watcher_input, &mut app.cursor_pos);
+                                app.fetch_watcher_candidates().await;
+                            }
+                            KeyCode::Char(c) => {
+                                input_insert(&mut app.watcher_input, &mut app.cursor_pos, c);
+                                app.fetch_watcher_candidates().await;
+                            }
+                            _ => {}
+                        },
+                        Mode::DetailRelated => match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => app.close_related_picker(),
+                            KeyCode::Up | KeyCode::Char('k') => app.related_move_up(),
+                            KeyCode::Down | KeyCode::Char('j') => app.related_move_down(),
+                            KeyCode::Enter => {
+                                app.set_detail_status("Loading...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_selected_related().await;
+                            }
                             _ => {}
                         },
-                        Mode::DetailTransition => match key.code {
+                        Mode::DetailTransition | Mode::TransitionPicker => match key.code {
                             KeyCode::Esc => app.cancel_transition(),
                             KeyCode::Up | KeyCode::Char('k') => app.transition_move_up(),
                             KeyCode::Down | KeyCode::Char('j') => app.transition_move_down(),
                             KeyCode::Enter => app.confirm_transition(),
                             _ => {}
                         },
-                        Mode::DetailConfirmTransition => match key.code {
-                            KeyCode::Char('y') | KeyCode::Enter => {
-                                app.set_detail_status("Transitioning...");
+                        Mode::DetailConfirmTransition | Mode::ConfirmTransition => match key.code {
+                            KeyCode::Enter => {
+                                app.set_transition_status("Transitioning...");
                                 terminal.draw(|f| ui::draw(f, &app))?;
                                 app.execute_transition().await;
                             }
-                            KeyCode::Char('n') | KeyCode::Esc => app.cancel_confirm_transition(),
+                            KeyCode::Esc => app.cancel_confirm_transition(),
+                            KeyCode::Tab | KeyCode::Down => app.transition_form_focus_next(),
+                            KeyCode::BackTab | KeyCode::Up => app.transition_form_focus_prev(),
+                            KeyCode::Left if app.transition_form_on_option() => {
+                                app.transition_form_cycle_option(false)
+                            }
+                            KeyCode::Right if app.transition_form_on_option() => {
+                                app.transition_form_cycle_option(true)
+                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right => {
+                                let len = app
+                                    .transition_form
+                                    .as_ref()
+                                    .and_then(|f| f.focused_text())
+                                    .map_or(0, |t| t.chars().count());
+                                if app.cursor_pos < len {
+                                    app.cursor_pos += 1;
+                                }
+                            }
+                            KeyCode::Backspace if !app.transition_form_on_option() => {
+                                if let Some(text) =
+                                    app.transition_form.as_mut().and_then(|f| f.focused_text_mut())
+                                {
+                                    input_backspace(text, &mut app.cursor_pos);
+                                }
+                            }
+                            KeyCode::Delete if !app.transition_form_on_option() => {
+                                if let Some(text) =
+                                    app.transition_form.as_mut().and_then(|f| f.focused_text_mut())
+                                {
+                                    input_delete(text, &mut app.cursor_pos);
+                                }
+                            }
+                            KeyCode::Char(c) if !app.transition_form_on_option() => {
+                                if let Some(text) =
+                                    app.transition_form.as_mut().and_then(|f| f.focused_text_mut())
+                                {
+                                    input_insert(text, &mut app.cursor_pos, c);
+                                }
+                            }
                             _ => {}
                         },
                         Mode::DetailAddingComment | Mode::DetailEditingComment => {
@@ -344,16 +786,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         }
                                     }
                                     KeyCode::Esc => app.cancel_comment_action(),
-                                    KeyCode::Left => {
-                                        if app.cursor_pos > 0 {
-                                            app.cursor_pos -= 1;
-                                        }
-                                    }
-                                    KeyCode::Right => {
-                                        if app.cursor_pos < app.comment_input.chars().count() {
-                                            app.cursor_pos += 1;
-                                        }
-                                    }
+                                    KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                                    KeyCode::Right if app.cursor_pos < app.comment_input.chars().count() => app.cursor_pos += 1,
                                     KeyCode::Home => app.cursor_pos = 0,
                                     KeyCode::End => {
                                         app.cursor_pos = app.comment_input.chars().count()
@@ -408,16 +842,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                 app.save_summary().await;
                             }
                             KeyCode::Esc => app.cancel_editing_summary(),
-                            KeyCode::Left => {
-                                if app.cursor_pos > 0 {
-                                    app.cursor_pos -= 1;
-                                }
-                            }
-                            KeyCode::Right => {
-                                if app.cursor_pos < app.summary_input.chars().count() {
-                                    app.cursor_pos += 1;
-                                }
-                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.summary_input.chars().count() => app.cursor_pos += 1,
                             KeyCode::Home => app.cursor_pos = 0,
                             KeyCode::End => {
                                 app.cursor_pos = app.summary_input.chars().count()
@@ -456,16 +882,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         app.long_note_input.insert(bp, '\n');
                                         app.cursor_pos = bp + 1;
                                     }
-                                    KeyCode::Left => {
-                                        if app.cursor_pos > 0 {
-                                            app.cursor_pos -= 1;
-                                        }
-                                    }
-                                    KeyCode::Right => {
-                                        if app.cursor_pos < app.long_note_input.len() {
-                                            app.cursor_pos += 1;
-                                        }
-                                    }
+                                    KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                                    KeyCode::Right if app.cursor_pos < app.long_note_input.len() => app.cursor_pos += 1,
                                     KeyCode::Up => {
                                         // Move cursor up one line
                                         let text = &app.long_note_input[..app.cursor_pos];
@@ -496,16 +914,12 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                                         let line_end = app.long_note_input[app.cursor_pos..].find('\n').map(|p| app.cursor_pos + p).unwrap_or(app.long_note_input.len());
                                         app.cursor_pos = line_end;
                                     }
-                                    KeyCode::Backspace => {
-                                        if app.cursor_pos > 0 {
-                                            app.cursor_pos -= 1;
-                                            app.long_note_input.remove(app.cursor_pos);
-                                        }
+                                    KeyCode::Backspace if app.cursor_pos > 0 => {
+                                        app.cursor_pos -= 1;
+                                        app.long_note_input.remove(app.cursor_pos);
                                     }
-                                    KeyCode::Delete => {
-                                        if app.cursor_pos < app.long_note_input.len() {
-                                            app.long_note_input.remove(app.cursor_pos);
-                                        }
+                                    KeyCode::Delete if app.cursor_pos < app.long_note_input.len() => {
+                                        app.long_note_input.remove(app.cursor_pos);
                                     }
                                     KeyCode::Char(c) => {
                                         let bp = app.cursor_pos.min(app.long_note_input.len());
@@ -519,16 +933,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Mode::EditingNote => match key.code {
                             KeyCode::Enter => app.save_status(),
                             KeyCode::Esc => app.cancel_edit(),
-                            KeyCode::Left => {
-                                if app.cursor_pos > 0 {
-                                    app.cursor_pos -= 1;
-                                }
-                            }
-                            KeyCode::Right => {
-                                if app.cursor_pos < app.note_input.chars().count() {
-                                    app.cursor_pos += 1;
-                                }
-                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.note_input.chars().count() => app.cursor_pos += 1,
                             KeyCode::Home => app.cursor_pos = 0,
                             KeyCode::End => {
                                 app.cursor_pos = app.note_input.chars().count()
@@ -556,6 +962,26 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             KeyCode::Up | KeyCode::Char('k') => app.sort_picker_up(),
                             KeyCode::Down | KeyCode::Char('j') => app.sort_picker_down(),
                             KeyCode::Enter => app.apply_sort(),
+                            KeyCode::Char(' ') => app.toggle_sort_key(),
+                            KeyCode::Char('r') => app.reverse_sort_key(),
+                            _ => {}
+                        },
+                        Mode::SprintPicker => match key.code {
+                            KeyCode::Esc => app.cancel_sprint_picker(),
+                            KeyCode::Up | KeyCode::Char('k') => app.sprint_picker_up(),
+                            KeyCode::Down | KeyCode::Char('j') => app.sprint_picker_down(),
+                            KeyCode::Enter => {
+                                app.set_status("Moving to sprint...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.move_targets_to_sprint().await;
+                            }
+                            _ => {}
+                        },
+                        Mode::GroupPicker => match key.code {
+                            KeyCode::Esc => app.cancel_group_picker(),
+                            KeyCode::Up | KeyCode::Char('k') => app.group_picker_up(),
+                            KeyCode::Down | KeyCode::Char('j') => app.group_picker_down(),
+                            KeyCode::Enter => app.apply_group(),
                             _ => {}
                         },
                         Mode::ColumnPicker => match key.code {
@@ -563,6 +989,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             KeyCode::Up | KeyCode::Char('k') => app.column_picker_up(),
                             KeyCode::Down | KeyCode::Char('j') => app.column_picker_down(),
                             KeyCode::Char(' ') => app.toggle_column_visibility(),
+                            KeyCode::Char('K') => app.move_column(true),
+                            KeyCode::Char('J') => app.move_column(false),
+                            KeyCode::Char('+') | KeyCode::Char('=') => app.resize_column(1),
+                            KeyCode::Char('-') => app.resize_column(-1),
                             _ => {}
                         },
                         Mode::FilterEditor => match key.code {
@@ -582,16 +1012,8 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         Mode::FilterAdding => match key.code {
                             KeyCode::Enter => app.confirm_add_filter(),
                             KeyCode::Esc => app.cancel_add_filter(),
-                            KeyCode::Left => {
-                                if app.cursor_pos > 0 {
-                                    app.cursor_pos -= 1;
-                                }
-                            }
-                            KeyCode::Right => {
-                                if app.cursor_pos < app.filter_input.chars().count() {
-                                    app.cursor_pos += 1;
-                                }
-                            }
+                            KeyCode::Left if app.cursor_pos > 0 => app.cursor_pos -= 1,
+                            KeyCode::Right if app.cursor_pos < app.filter_input.chars().count() => app.cursor_pos += 1,
                             KeyCode::Home => app.cursor_pos = 0,
                             KeyCode::End => {
                                 app.cursor_pos = app.filter_input.chars().count()
@@ -614,6 +1036,29 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                             }
                             _ => {}
                         },
+                        Mode::Stats => match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => app.close_stats(),
+                            KeyCode::Left | KeyCode::Right | KeyCode::Char('r') => {
+                                match key.code {
+                                    KeyCode::Left => app.cycle_stats_period(false),
+                                    KeyCode::Right => app.cycle_stats_period(true),
+                                    _ => {}
+                                }
+                                app.set_status("Loading stats...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_stats().await;
+                            }
+                            _ => {}
+                        },
+                        Mode::Reports => match key.code {
+                            KeyCode::Esc | KeyCode::Char('q') => app.close_reports(),
+                            KeyCode::Char('r') => {
+                                app.set_status("Loading reports...");
+                                terminal.draw(|f| ui::draw(f, &app))?;
+                                app.open_reports().await;
+                            }
+                            _ => {}
+                        },
                         Mode::Notifications => match key.code {
                             KeyCode::Esc => app.close_notifications(),
                             KeyCode::Up | KeyCode::Char('k') => app.notifications_move_up(),
@@ -645,6 +1090,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         | Mode::DetailConfirmDelete
                         | Mode::DetailTransition
                         | Mode::DetailConfirmTransition
+                        | Mode::DetailRelated
+                        | Mode::DetailWatchers
+                        | Mode::DetailAddingWatcher
+                        | Mode::DetailSearching
                         | Mode::DetailEditingSummary => app.detail_scroll_up(),
                         Mode::Normal | Mode::Searching => app.move_up(),
                         Mode::Notifications => app.notifications_move_up(),
@@ -657,6 +1106,10 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
                         | Mode::DetailConfirmDelete
                         | Mode::DetailTransition
                         | Mode::DetailConfirmTransition
+                        | Mode::DetailRelated
+                        | Mode::DetailWatchers
+                        | Mode::DetailAddingWatcher
+                        | Mode::DetailSearching
                         | Mode::DetailEditingSummary => app.detail_scroll_down(),
                         Mode::Normal | Mode::Searching => app.move_down(),
                         Mode::Notifications => app.notifications_move_down(),
@@ -1,6 +1,8 @@
 use std::collections::HashMap;
 use std::fs;
 
+use serde::{Deserialize, Serialize};
+
 use crate::config;
 
 fn notes_path() -> std::path::PathBuf {
@@ -104,3 +106,121 @@ pub fn save_dismissed_notifications(ids: &std::collections::HashSet<String>) {
         let _ = fs::write(dismissed_notifications_path(), json);
     }
 }
+
+/// An issue opened in the detail view, most recent first in the store.
+#[derive(Deserialize, Serialize, Clone)]
+pub struct RecentIssue {
+    pub key: String,
+    pub summary: String,
+}
+
+fn recent_path() -> std::path::PathBuf {
+    config::config_dir().join("recent.json")
+}
+
+pub fn load_recent() -> Vec<RecentIssue> {
+    let contents = match fs::read_to_string(recent_path()) {
+        Ok(c) => c,
+        Err(_) => return migrate_quick_open_history(),
+    };
+    serde_json::from_str(&contents).unwrap_or_default()
+}
+
+/// Migrate from the older key-only quick-open history, removing its file.
+fn migrate_quick_open_history() -> Vec<RecentIssue> {
+    let old_path = config::config_dir().join("quick_open_history.json");
+    let contents = match fs::read_to_string(&old_path) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    let keys: Vec<String> = serde_json::from_str(&contents).unwrap_or_default();
+    let recent: Vec<RecentIssue> = keys
+        .into_iter()
+        .map(|key| RecentIssue {
+            key,
+            summary: String::new(),
+        })
+        .collect();
+    save_recent(&recent);
+    let _ = fs::remove_file(old_path);
+    recent
+}
+
+pub fn save_recent(recent: &[RecentIssue]) {
+    if let Ok(json) = serde_json::to_string(recent) {
+        let _ = fs::write(recent_path(), json);
+    }
+}
+
+fn pinned_path() -> std::path::PathBuf {
+    config::config_dir().join("pinned.json")
+}
+
+pub fn load_pinned() -> Vec<String> {
+    let contents = match fs::read_to_string(pinned_path()) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    serde_json::from_str(&contents).unwrap_or_default()
+}
+
+pub fn save_pinned(keys: &[String]) {
+    if let Ok(json) = serde_json::to_string(keys) {
+        let _ = fs::write(pinned_path(), json);
+    }
+}
+
+fn collapsed_path() -> std::path::PathBuf {
+    config::config_dir().join("collapsed.json")
+}
+
+pub fn load_collapsed() -> std::collections::HashSet<String> {
+    let contents = match fs::read_to_string(collapsed_path()) {
+        Ok(c) => c,
+        Err(_) => return std::collections::HashSet::new(),
+    };
+    serde_json::from_str(&contents).unwrap_or_default()
+}
+
+pub fn save_collapsed(keys: &std::collections::HashSet<String>) {
+    if let Ok(json) = serde_json::to_string(keys) {
+        let _ = fs::write(collapsed_path(), json);
+    }
+}
+
+fn manual_order_path() -> std::path::PathBuf {
+    config::config_dir().join("manual_order.json")
+}
+
+/// Place of an issue in the manual order.
+#[derive(Serialize, Deserialize)]
+pub struct ManualEntry {
+    pub key: String,
+    /// Unix seconds of the last fetch that included the issue
+    pub last_seen: i64,
+}
+
+pub fn load_manual_order() -> Vec<ManualEntry> {
+    let contents = match fs::read_to_string(manual_order_path()) {
+        Ok(c) => c,
+        Err(_) => return Vec::new(),
+    };
+    if let Ok(entries) = serde_json::from_str::<Vec<ManualEntry>>(&contents) {
+        return entries;
+    }
+    // Migrate from plain keys — count them as seen now
+    if let Ok(keys) = serde_json::from_str::<Vec<String>>(&contents) {
+        let now = crate::jira::now_timestamp();
+        let entries: Vec<ManualEntry> =
+            keys.into_iter().map(|key| ManualEntry { key, last_seen: now }).collect();
+        save_manual_order(&entries);
+        return entries;
+    }
+    Vec::new()
+}
+
+pub fn save_manual_order(entries: &[ManualEntry]) {
+    if let Ok(json) = serde_json::to_string(entries) {
+        let _ = fs::write(manual_order_path(), json);
+    }
+}
@@ -0,0 +1,220 @@
+use crate::config::Config;
+
+/// Field a `name:value` search term filters on.
+#[derive(Clone, PartialEq)]
+pub enum Field {
+    Status,
+    Type,
+    Priority,
+    Assignee,
+    Label,
+    Note,
+    Highlight,
+    Muted,
+    /// Configured custom field, by friendly name
+    Custom(String),
+}
+
+/// Built-in fields by term name, with aliases, in completion order.
+pub const FIELD_NAMES: [(&str, &[&str], Field); 8] = [
+    ("status", &["s"], Field::Status),
+    ("type", &["t"], Field::Type),
+    ("prio", &["priority", "p"], Field::Priority),
+    ("assignee", &["a"], Field::Assignee),
+    ("label", &["l"], Field::Label),
+    ("note", &["n"], Field::Note),
+    ("hl", &["highlight"], Field::Highlight),
+    ("muted", &[], Field::Muted),
+];
+
+/// Comparison of a `prio:` term; other fields only match by substring.
+#[derive(Clone, Copy, PartialEq)]
+pub enum Op {
+    Contains,
+    Ge,
+    Le,
+    Gt,
+    Lt,
+}
+
+pub struct Filter {
+    pub field: Field,
+    pub op: Op,
+    /// Lowercased
+    pub value: String,
+    /// Written as `-name:value`
+    pub negated: bool,
+}
+
+impl Filter {
+    /// Whether `text` contains the value, with `_` also standing for a space
+    /// since terms can't contain spaces: `in_review` finds "In Review" and
+    /// `tech_debt` still finds a `tech_debt` label.
+    pub fn text_matches(&self, text: &str) -> bool {
+        let lower = text.to_lowercase();
+        lower.contains(&self.value) || lower.contains(&self.value.replace('_', " "))
+    }
+}
+
+/// Parsed search input.
+pub struct Query {
+    pub filters: Vec<Filter>,
+    /// Lowercased `-word` terms that key and summary must not contain
+    pub excluded: Vec<String>,
+    /// Remaining free text, fuzzy-matched against key and summary
+    pub text: String,
+}
+
+impl Query {
+    pub fn parse(input: &str, config: &Config) -> Query {
+        let mut query = Query {
+            filters: Vec::new(),
+            excluded: Vec::new(),
+            text: String::new(),
+        };
+        let mut words = Vec::new();
+        for term in input.split_whitespace() {
+            let (negated, body) = match term.strip_prefix('-') {
+                Some(rest) if !rest.is_empty() => (true, rest),
+                _ => (false, term),
+            };
+            match body.split_once(':').and_then(|(name, value)| {
+                Some((field_by_name(name, config)?, value))
+            }) {
+                Some((field, value)) => {
+                    let (op, value) = split_op(&field, value);
+                    query.filters.push(Filter {
+                        field,
+                        op,
+                        value: value.to_lowercase(),
+                        negated,
+                    });
+                }
+                None if negated => query.excluded.push(body.to_lowercase()),
+                None => words.push(term),
+            }
+        }
+        query.text = words.join(" ");
+        query
+    }
+}
+
+/// Field for a term name, built-in names first. Spaces in custom field
+/// names are written as `_`.
+pub fn field_by_name(name: &str, config: &Config) -> Option<Field> {
+    builtin_field(name).or_else(|| {
+        let custom = config.custom_field(&name.replace('_', " "))?;
+        Some(Field::Custom(custom.name.clone()))
+    })
+}
+
+/// Built-in field by name or alias.
+pub fn builtin_field(name: &str) -> Option<Field> {
+    let lower = name.to_lowercase();
+    FIELD_NAMES
+        .iter()
+        .find(|(n, aliases, _)| *n == lower || aliases.contains(&lower.as_str()))
+        .map(|(_, _, field)| field.clone())
+}
+
+fn split_op<'a>(field: &Field, value: &'a str) -> (Op, &'a str) {
+    if *field != Field::Priority {
+        return (Op::Contains, value);
+    }
+    for (prefix, op) in [(">=", Op::Ge), ("<=", Op::Le), (">", Op::Gt), ("<", Op::Lt)] {
+        if let Some(rest) = value.strip_prefix(prefix) {
+            return (op, rest);
+        }
+    }
+    (Op::Contains, value)
+}
+
+/// Name a custom field is written as in a search term.
+pub fn custom_term_name(name: &str) -> String {
+    name.to_lowercase().replace(' ', "_")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> Config {
+        serde_json::from_value(serde_json::json!({
+            "jira_url": "https://example.atlassian.net",
+            "email": "me@example.com",
+            "api_token": "token",
+            "custom_fields": [{ "name": "Customer Tier", "id": "customfield_10042" }],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn split_op_only_compares_priority() {
+        assert!(split_op(&Field::Priority, ">=high") == (Op::Ge, "high"));
+        assert!(split_op(&Field::Priority, "<=low") == (Op::Le, "low"));
+        assert!(split_op(&Field::Priority, ">high") == (Op::Gt, "high"));
+        assert!(split_op(&Field::Priority, "<low") == (Op::Lt, "low"));
+        assert!(split_op(&Field::Priority, "high") == (Op::Contains, "high"));
+        assert!(split_op(&Field::Status, ">=done") == (Op::Contains, ">=done"));
+    }
+
+    #[test]
+    fn parses_priority_comparison() {
+        let query = Query::parse("prio:>=High", &config());
+        assert_eq!(query.filters.len(), 1);
+        let filter = &query.filters[0];
+        assert!(filter.field == Field::Priority);
+        assert!(filter.op == Op::Ge);
+        assert_eq!(filter.value, "high");
+        assert!(!filter.negated);
+        assert!(query.text.is_empty());
+    }
+
+    #[test]
+    fn parses_negation() {
+        let query = Query::parse("-s:done -Flaky login -", &config());
+        assert_eq!(query.filters.len(), 1);
+        assert!(query.filters[0].field == Field::Status);
+        assert!(query.filters[0].negated);
+        assert_eq!(query.filters[0].value, "done");
+        assert_eq!(query.excluded, vec!["flaky".to_string()]);
+        // A lone `-` is plain text
+        assert_eq!(query.text, "login -");
+    }
+
+    #[test]
+    fn underscores_stand_for_spaces_in_custom_names() {
+        let query = Query::parse("customer_tier:gold", &config());
+        assert_eq!(query.filters.len(), 1);
+        assert!(query.filters[0].field == Field::Custom("Customer Tier".to_string()));
+        assert_eq!(query.filters[0].value, "gold");
+        assert_eq!(custom_term_name("Customer Tier"), "customer_tier");
+    }
+
+    #[test]
+    fn underscores_match_literally_or_as_spaces() {
+        let query = Query::parse("label:tech_debt s:in_review", &config());
+        let (label, status) = (&query.filters[0], &query.filters[1]);
+        assert!(label.field == Field::Label);
+        assert!(label.text_matches("tech_debt"));
+        assert!(label.text_matches("Tech Debt"));
+        assert!(!label.text_matches("techdebt"));
+        assert!(status.text_matches("In Review"));
+    }
+
+    #[test]
+    fn unknown_names_fall_back_to_text() {
+        let query = Query::parse("team:core http://host -nope:x", &config());
+        assert!(query.filters.is_empty());
+        assert_eq!(query.text, "team:core http://host");
+        assert_eq!(query.excluded, vec!["nope:x".to_string()]);
+    }
+
+    #[test]
+    fn built_in_names_win_over_custom_fields() {
+        let mut config = config();
+        config.custom_fields[0].name = "Status".to_string();
+        assert!(field_by_name("status", &config) == Some(Field::Status));
+        assert!(field_by_name("P", &config) == Some(Field::Priority));
+    }
+}
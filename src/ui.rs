@@ -1,16 +1,20 @@
 use ratatui::layout::{Constraint, Layout, Rect};
 use ratatui::style::{Color, Modifier, Style};
 use ratatui::text::{Line, Span, Text};
+use ratatui::symbols::Marker;
 use ratatui::widgets::{
-    Block, Borders, Cell, Clear, Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState,
-    Table, TableState, Wrap,
+    Axis, Bar, BarChart, BarGroup, Block, Borders, Cell, Chart, Clear, Dataset, GraphType,
+    Paragraph, Row, Scrollbar, ScrollbarOrientation, ScrollbarState, Table, TableState, Wrap,
 };
 use ratatui::Frame;
 
 use crate::app::{
-    fuzzy_match, App, Column, DetailRenderCache, HighlightColor, Mode, SortCriteria,
+    find_line_matches, fuzzy_match, App, Column, DetailRenderCache, DetailTab, DisplayRow, GroupBy, HighlightColor, Mode,
     HIGHLIGHT_OPTIONS,
 };
+use crate::flow;
+use crate::github::GithubPR;
+use crate::jira::{self, IssueDetail, JiraIssue, RelatedIssue, TransitionFieldKind};
 
 const ZEBRA_DARK: Color = Color::Rgb(30, 30, 40);
 const HIGHLIGHT_BG: Color = Color::Rgb(55, 55, 80);
@@ -21,6 +25,8 @@ const ACCENT: Color = Color::Rgb(180, 180, 255);
 const NOTIF_BG: Color = Color::Rgb(6, 8, 20);
 const NOTIF_ZEBRA: Color = Color::Rgb(16, 18, 35);
 const DETAIL_BG: Color = Color::Rgb(10, 8, 22);
+const MARKED_BG: Color = Color::Rgb(35, 55, 90);
+const PIN_COLOR: Color = Color::Rgb(255, 200, 60);
 
 fn issue_type_icon(issue_type: &str) -> (&'static str, Color) {
     match issue_type {
@@ -47,7 +53,7 @@ fn split_at_char_pos(s: &str, pos: usize) -> (&str, &str) {
 
 fn visible_input(input: &str, cursor_pos: usize, max_chars: usize) -> String {
     let char_count = input.chars().count();
-    if char_count + 1 <= max_chars {
+    if char_count < max_chars {
         let (before, after) = split_at_char_pos(input, cursor_pos);
         return format!("{before}|{after}");
     }
@@ -105,6 +111,12 @@ pub fn draw(f: &mut Frame, app: &App) {
     };
     if app.mode == Mode::Notifications {
         draw_notifications_view(f, app, table_area);
+    } else if app.mode == Mode::Reports {
+        draw_reports_view(f, app, table_area);
+    } else if app.mode == Mode::Stats {
+        draw_stats_view(f, app, table_area);
+    } else if app.board_view {
+        draw_board(f, app, table_area);
     } else {
         draw_table(f, app, table_area);
     }
@@ -129,6 +141,10 @@ pub fn draw(f: &mut Frame, app: &App) {
         | Mode::DetailEditingComment
         | Mode::DetailConfirmDelete
         | Mode::DetailTransition
+        | Mode::DetailRelated
+        | Mode::DetailWatchers
+        | Mode::DetailAddingWatcher
+        | Mode::DetailSearching
         | Mode::DetailEditingSummary => {
             dim_background(f);
             draw_detail_modal(f, app);
@@ -138,11 +154,6 @@ pub fn draw(f: &mut Frame, app: &App) {
             draw_detail_modal(f, app);
             draw_confirm_transition_modal(f, app);
         }
-        Mode::DetailPRList => {
-            dim_background(f);
-            draw_detail_modal(f, app);
-            draw_pr_list_modal(f, app);
-        }
         Mode::EditingLongNote => {
             dim_background(f);
             draw_long_note_modal(f, app);
@@ -155,6 +166,22 @@ pub fn draw(f: &mut Frame, app: &App) {
             dim_background(f);
             draw_sort_picker_modal(f, app);
         }
+        Mode::GroupPicker => {
+            dim_background(f);
+            draw_group_picker_modal(f, app);
+        }
+        Mode::SprintPicker => {
+            dim_background(f);
+            draw_sprint_picker_modal(f, app);
+        }
+        Mode::TransitionPicker => {
+            dim_background(f);
+            draw_transition_picker_modal(f, app);
+        }
+        Mode::ConfirmTransition => {
+            dim_background(f);
+            draw_confirm_transition_modal(f, app);
+        }
         Mode::ColumnPicker => {
             dim_background(f);
             draw_column_picker_modal(f, app);
@@ -163,6 +190,18 @@ pub fn draw(f: &mut Frame, app: &App) {
             dim_background(f);
             draw_confirm_quit_modal(f);
         }
+        Mode::QuickOpen => {
+            dim_background(f);
+            draw_quick_open_modal(f, app);
+        }
+        Mode::AssignPicker => {
+            dim_background(f);
+            draw_assign_modal(f, app);
+        }
+        Mode::AddingLabel => {
+            dim_background(f);
+            draw_label_modal(f, app);
+        }
         _ => {}
     }
 
@@ -268,23 +307,104 @@ fn truncate(s: &str, max: usize) -> String {
 
 // ── Main table ──────────────────────────────────────────────
 
+/// Indentation for each row. In tree order siblings get `├`/`└` and open
+/// ancestor levels a `│`; otherwise rows are only indented by depth.
+fn tree_prefixes(rows: &[DisplayRow], tree_order: bool) -> Vec<String> {
+    if !tree_order {
+        return rows
+            .iter()
+            .map(|r| match r.depth {
+                0 => String::new(),
+                d => format!("{}└ ", "  ".repeat(d as usize)),
+            })
+            .collect();
+    }
+    // Walk bottom-up; `open[l]` is true while a later sibling at depth `l` follows
+    let mut open: Vec<bool> = Vec::new();
+    let mut prefixes = vec![String::new(); rows.len()];
+    for (i, row) in rows.iter().enumerate().rev() {
+        let depth = row.depth as usize;
+        if open.len() <= depth {
+            open.resize(depth + 1, false);
+        }
+        if depth > 0 {
+            let mut prefix = String::from("  ");
+            for &continues in &open[1..depth] {
+                prefix.push_str(if continues { "│ " } else { "  " });
+            }
+            prefix.push_str(if open[depth] { "├ " } else { "└ " });
+            prefixes[i] = prefix;
+        }
+        open[depth] = true;
+        open.truncate(depth + 1);
+    }
+    prefixes
+}
+
+/// Untruncated text of an optional table column.
+fn column_text(app: &App, issue: &JiraIssue, col: &Column, now: i64) -> String {
+    match col {
+        Column::Assignee => issue.assignee.clone(),
+        Column::Reporter => issue
+            .reporter
+            .split_whitespace()
+            .next()
+            .unwrap_or(&issue.reporter)
+            .to_string(),
+        Column::Priority => issue.priority.clone(),
+        Column::IssueType => issue.issue_type.clone(),
+        Column::Status => issue.status.clone(),
+        Column::Resolution => issue.resolution.clone(),
+        Column::Created => issue.created.clone(),
+        Column::Updated => jira::parse_timestamp(&issue.updated)
+            .map(|ts| jira::format_age(now - ts))
+            .unwrap_or_default(),
+        Column::Due => issue.due.clone(),
+        Column::StoryPoints => issue.story_points.map(format_points).unwrap_or_default(),
+        Column::Sprint => match &app.sprint {
+            Some(sprint) if app.sprint_keys.contains(&issue.key) => sprint.name.clone(),
+            _ => String::new(),
+        },
+        Column::Labels => issue.labels.join(", "),
+        Column::Components => issue.components.join(", "),
+        Column::Custom(name) => issue.custom.get(name).cloned().unwrap_or_default(),
+    }
+}
+
+/// `3`, `0.5`
+fn format_points(points: f64) -> String {
+    if points.fract() == 0.0 {
+        format!("{points:.0}")
+    } else {
+        format!("{points}")
+    }
+}
+
+/// Past its due date and not yet done.
+fn is_overdue(issue: &JiraIssue, now: i64) -> bool {
+    issue.status_category_key != "done"
+        && jira::parse_timestamp(&issue.due).is_some_and(|due| due + 86400 <= now)
+}
+
 fn draw_table(f: &mut Frame, app: &App, area: Rect) {
-    let col_assignee = app.show_all_parents && app.is_column_visible(Column::Assignee);
-    let col_reporter = app.is_column_visible(Column::Reporter);
-    let col_status = app.is_column_visible(Column::Status);
-    const ASSIGNEE_W: u16 = 16;
-    const REPORTER_W: u16 = 10;
-    const STATUS_W: u16 = 16;
+    let columns: Vec<(Column, u16)> = app
+        .visible_columns()
+        .into_iter()
+        .map(|c| {
+            let width = app.column_width(&c);
+            (c, width)
+        })
+        .collect();
     const COL_SPACING: u16 = 2;
     const BORDERS: u16 = 2;
     const HIGHLIGHT_SYM: u16 = 2;
 
-    let mut num_cols: u16 = 2; // Work + My Status always present
-    let mut fixed: u16 = BORDERS + HIGHLIGHT_SYM;
-    if col_assignee { num_cols += 1; fixed += ASSIGNEE_W; }
-    if col_reporter { num_cols += 1; fixed += REPORTER_W; }
-    if col_status { num_cols += 1; fixed += STATUS_W; }
-    fixed += COL_SPACING * (num_cols - 1);
+    // Work + My Status always present
+    let num_cols = columns.len() as u16 + 2;
+    let fixed = BORDERS
+        + HIGHLIGHT_SYM
+        + columns.iter().map(|(_, w)| w).sum::<u16>()
+        + COL_SPACING * (num_cols - 1);
 
     let remaining = area.width.saturating_sub(fixed);
     let work_w = ((remaining as u32 * 3 / 4) as u16).max(20);
@@ -292,25 +412,26 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
 
     let work_chars = work_w as usize;
     let notes_chars = notes_w as usize;
-    let assignee_chars = ASSIGNEE_W as usize;
-    let reporter_chars = REPORTER_W as usize;
-    let status_chars = STATUS_W as usize;
+    let now = jira::now_timestamp();
 
     let header_style = Style::default()
         .fg(Color::Rgb(180, 180, 200))
         .add_modifier(Modifier::BOLD);
 
     let mut header_cells = vec![Cell::from("Work")];
-    if col_assignee { header_cells.push(Cell::from("Assignee")); }
-    if col_reporter { header_cells.push(Cell::from("Reporter")); }
-    if col_status { header_cells.push(Cell::from("Status")); }
+    header_cells.extend(columns.iter().map(|(c, _)| Cell::from(c.label())));
     header_cells.push(Cell::from("My Status"));
 
     let header = Row::new(header_cells)
         .style(header_style)
         .bottom_margin(1);
 
-    let rows: Vec<Row> = app
+    let tree_order =
+        app.is_default_sort() && app.group_by == GroupBy::None && !app.search_ranked();
+    let tree_prefixes = tree_prefixes(&app.rows, tree_order);
+    let search_text = app.search_text();
+
+    let issue_rows: Vec<Row> = app
         .rows
         .iter()
         .enumerate()
@@ -319,10 +440,39 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
             let is_parent = display_row.is_context_parent;
 
             let (icon, icon_color) = issue_type_icon(&issue.issue_type);
-            let depth_prefix = if display_row.depth > 0 { "  └ " } else { "" };
-            let key_summary = format!("{} {}", issue.key, issue.summary);
-            let arrow = if i == app.selected { "▶ " } else { "  " };
-            let prefix_len = 2 + depth_prefix.chars().count() + icon.chars().count() + 1;
+            // Searching shows matches inside collapsed groups, so no roll-up then
+            let collapsed = if app.search_input.is_empty() {
+                app.collapsed_summary(display_row)
+            } else {
+                None
+            };
+            let depth_prefix = match collapsed {
+                Some(_) => format!("{}▸ ", tree_prefixes[i]),
+                None => tree_prefixes[i].clone(),
+            };
+            let pin_prefix = if display_row.is_pinned { "★ " } else { "" };
+            // Found by Jira's text search in description or comments
+            let text_search_prefix = if display_row.from_text_search { "⌕ " } else { "" };
+            let key_summary = match &collapsed {
+                Some((count, statuses)) => {
+                    format!("{} {}  [+{count}: {statuses}]", issue.key, issue.summary)
+                }
+                None => format!("{} {}", issue.key, issue.summary),
+            };
+            let is_marked = app.marked.contains(&issue.key);
+            let arrow = if i == app.selected {
+                "▶ "
+            } else if is_marked {
+                "✓ "
+            } else {
+                "  "
+            };
+            let prefix_len = 2
+                + pin_prefix.chars().count()
+                + text_search_prefix.chars().count()
+                + depth_prefix.chars().count()
+                + icon.chars().count()
+                + 1;
 
             let note = app.notes.get(&issue.key).cloned().unwrap_or_default();
             let has_long_note = app.long_notes.contains_key(&issue.key);
@@ -334,16 +484,14 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
                 format!("{}{}", note_prefix, truncate(&note, avail))
             };
 
-            let reporter_first = issue.reporter.split_whitespace().next().unwrap_or(&issue.reporter);
-            let reporter_text = truncate(reporter_first, reporter_chars);
-            let status_text = truncate(&issue.status, status_chars);
-
             let is_muted = app.muted_keys.contains(&issue.key);
             let base_fg = if is_parent || is_muted { DIM } else { Color::White };
             let base_style = Style::default().fg(base_fg);
 
             let highlight_color = app.highlighted_keys.get(&issue.key).and_then(|s| HighlightColor::from_str(s));
-            let bg = if let Some(color) = highlight_color {
+            let bg = if is_marked {
+                MARKED_BG
+            } else if let Some(color) = highlight_color {
                 match color {
                     HighlightColor::Orange => HIGHLIGHT_ORANGE_BG,
                     HighlightColor::Green => HIGHLIGHT_GREEN_BG,
@@ -367,8 +515,8 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
             let key_summary_text = truncate(&key_summary, work_chars.saturating_sub(prefix_len));
 
             // Build Work cell with optional fuzzy match highlighting
-            let text_spans = if !app.search_input.is_empty() {
-                if let Some(positions) = fuzzy_match(&key_summary, &app.search_input) {
+            let text_spans = if !search_text.is_empty() && !display_row.from_text_search {
+                if let Some(positions) = fuzzy_match(&key_summary, &search_text) {
                     // Map positions from key_summary to key_summary_text
                     let max_pos = key_summary_text.chars().count();
                     let highlight_set: std::collections::HashSet<usize> =
@@ -404,7 +552,9 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
 
             let mut work_spans = vec![
                 Span::styled(arrow, Style::default().fg(ACCENT).bg(bg)),
-                Span::styled(depth_prefix.to_string(), base_style.bg(bg)),
+                Span::styled(pin_prefix, Style::default().fg(PIN_COLOR).bg(bg)),
+                Span::styled(text_search_prefix, Style::default().fg(Color::Rgb(140, 200, 255)).bg(bg)),
+                Span::styled(depth_prefix, Style::default().fg(DIM).bg(bg)),
                 Span::styled(icon.to_string(), Style::default().fg(ic).bg(bg)),
                 Span::styled(" ".to_string(), base_style.bg(bg)),
             ];
@@ -423,22 +573,20 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
 
             let work_cell = cell!(Line::from(work_spans));
             let mut cells = vec![work_cell];
-            if col_assignee {
-                let assignee_style = if is_parent || is_muted {
-                    Style::default().fg(DIM)
-                } else {
-                    Style::default().fg(Color::DarkGray)
+            for (col, width) in &columns {
+                let text = truncate(&column_text(app, issue, col, now), *width as usize);
+                let style = match col {
+                    Column::Status => s_style,
+                    _ if is_parent || is_muted => Style::default().fg(DIM).bg(bg),
+                    Column::Assignee | Column::Created | Column::Updated => {
+                        Style::default().fg(Color::DarkGray).bg(bg)
+                    }
+                    Column::Due if is_overdue(issue, now) => {
+                        Style::default().fg(Color::Rgb(255, 100, 100)).bg(bg)
+                    }
+                    _ => base_style.bg(bg),
                 };
-                cells.push(cell!(Line::from(Span::styled(
-                    truncate(&issue.assignee, assignee_chars),
-                    assignee_style.bg(bg),
-                ))));
-            }
-            if col_reporter {
-                cells.push(cell!(Line::from(Span::styled(reporter_text, base_style.bg(bg)))));
-            }
-            if col_status {
-                cells.push(cell!(Line::from(Span::styled(status_text, s_style))));
+                cells.push(cell!(Line::from(Span::styled(text, style))));
             }
             cells.push(cell!(Line::from(Span::styled(note_text, note_style))));
 
@@ -447,10 +595,36 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
         })
         .collect();
 
+    // Section header before each group; the selection shifts past them
+    let group_labels: Vec<Option<String>> = app.rows.iter().map(|r| app.group_label(r)).collect();
+    let mut group_counts: std::collections::HashMap<&str, usize> =
+        std::collections::HashMap::new();
+    for label in group_labels.iter().flatten() {
+        *group_counts.entry(label.as_str()).or_default() += 1;
+    }
+    let group_style = Style::default().fg(ACCENT).add_modifier(Modifier::BOLD);
+    let mut rows: Vec<Row> = Vec::with_capacity(issue_rows.len());
+    let mut selected_row = app.selected;
+    let mut prev_label: Option<&str> = None;
+    for (i, row) in issue_rows.into_iter().enumerate() {
+        if let Some(label) = group_labels[i].as_deref() {
+            if prev_label != Some(label) {
+                let count = group_counts.get(label).copied().unwrap_or(0);
+                rows.push(Row::new(vec![Cell::from(Line::from(Span::styled(
+                    format!("▾ {label} ({count})"),
+                    group_style,
+                )))]));
+                if i <= app.selected {
+                    selected_row += 1;
+                }
+            }
+        }
+        prev_label = group_labels[i].as_deref();
+        rows.push(row);
+    }
+
     let mut widths = vec![Constraint::Length(work_w)];
-    if col_assignee { widths.push(Constraint::Length(ASSIGNEE_W)); }
-    if col_reporter { widths.push(Constraint::Length(REPORTER_W)); }
-    if col_status { widths.push(Constraint::Length(STATUS_W)); }
+    widths.extend(columns.iter().map(|(_, w)| Constraint::Length(*w)));
     widths.push(Constraint::Length(notes_w));
 
     let block = Block::default()
@@ -491,13 +665,112 @@ fn draw_table(f: &mut Frame, app: &App, area: Rect) {
 
         let mut state = TableState::default();
         if !app.rows.is_empty() {
-            state.select(Some(app.selected));
+            state.select(Some(selected_row));
         }
 
         f.render_stateful_widget(table, area, &mut state);
     }
 }
 
+// ── Board view ───────────────────────────────────────────────
+
+fn draw_board(f: &mut Frame, app: &App, area: Rect) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(60, 60, 80)))
+        .title(Line::from(vec![
+            Span::styled(
+                " Mindful Jira ",
+                Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("board ", Style::default().fg(Color::Rgb(80, 80, 100))),
+        ]));
+    let inner = block.inner(area);
+    f.render_widget(block, area);
+
+    let lanes = app.board_lanes();
+    if lanes.is_empty() {
+        f.render_widget(
+            Paragraph::new(Span::styled("  No issues", Style::default().fg(Color::DarkGray))),
+            inner,
+        );
+        return;
+    }
+    let selected_lane = app.board_position(&lanes).map(|(l, _)| l);
+    let constraints = vec![Constraint::Ratio(1, lanes.len() as u32); lanes.len()];
+    let lane_areas = Layout::horizontal(constraints).split(inner);
+
+    // Key line, summary line, optional note line, then a gap
+    const CARD_H: usize = 4;
+    let note_style = Style::default().fg(Color::Rgb(140, 200, 255));
+
+    for (l, (lane, lane_area)) in lanes.iter().zip(lane_areas.iter()).enumerate() {
+        let border = if selected_lane == Some(l) { ACCENT } else { Color::Rgb(60, 60, 80) };
+        let lane_block = Block::default()
+            .borders(Borders::ALL)
+            .border_style(Style::default().fg(border))
+            .title(Span::styled(
+                format!(" {} ({}) ", lane.name, lane.cards.len()),
+                status_style(&lane.name).add_modifier(Modifier::BOLD),
+            ));
+        let lane_inner = lane_block.inner(*lane_area);
+        f.render_widget(lane_block, *lane_area);
+
+        let width = lane_inner.width as usize;
+        let visible = (lane_inner.height as usize / CARD_H).max(1);
+        let selected_pos = lane.cards.iter().position(|&c| c == app.selected);
+        let offset = selected_pos.map_or(0, |p| (p + 1).saturating_sub(visible));
+
+        let mut lines: Vec<Line> = Vec::new();
+        for &idx in lane.cards.iter().skip(offset).take(visible) {
+            let issue = &app.rows[idx].issue;
+            let bg = if idx == app.selected {
+                HIGHLIGHT_BG
+            } else if app.marked.contains(&issue.key) {
+                MARKED_BG
+            } else {
+                Color::Reset
+            };
+            let (icon, icon_color) = issue_type_icon(&issue.issue_type);
+            let (pflag, _, pflag_color) = priority_flag(&issue.priority);
+            let key_w = width.saturating_sub(icon.chars().count() + pflag.chars().count() + 3);
+            let pad = |used: usize| " ".repeat(width.saturating_sub(used));
+            let key_text = truncate(&issue.key, key_w);
+            let used = icon.chars().count() + key_text.chars().count() + pflag.chars().count() + 3;
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {icon} "), Style::default().fg(icon_color).bg(bg)),
+                Span::styled(
+                    key_text,
+                    Style::default().fg(Color::White).bg(bg).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(format!(" {pflag}"), Style::default().fg(pflag_color).bg(bg)),
+                Span::styled(pad(used), Style::default().bg(bg)),
+            ]));
+            let summary = truncate(&issue.summary, width.saturating_sub(1));
+            let used = summary.chars().count() + 1;
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {summary}"), Style::default().fg(Color::Rgb(200, 200, 210)).bg(bg)),
+                Span::styled(pad(used), Style::default().bg(bg)),
+            ]));
+            let note = app.notes.get(&issue.key).map(String::as_str).unwrap_or("");
+            let note = truncate(note, width.saturating_sub(1));
+            let used = note.chars().count() + 1;
+            lines.push(Line::from(vec![
+                Span::styled(format!(" {note}"), note_style.bg(bg)),
+                Span::styled(pad(used), Style::default().bg(bg)),
+            ]));
+            lines.push(Line::from(""));
+        }
+        if offset > 0 {
+            lines.insert(0, Line::from(Span::styled(
+                format!(" ↑ {offset} more"),
+                Style::default().fg(DIM),
+            )));
+        }
+        f.render_widget(Paragraph::new(lines), lane_inner);
+    }
+}
+
 // ── Confirm browser modal ────────────────────────────────────
 
 fn draw_confirm_browser_modal(f: &mut Frame, app: &App) {
@@ -582,12 +855,212 @@ fn draw_confirm_quit_modal(f: &mut Frame) {
     f.render_widget(Paragraph::new(lines), inner);
 }
 
+// ── Quick open modal ─────────────────────────────────────────
+
+fn draw_quick_open_modal(f: &mut Frame, app: &App) {
+    let candidates = app.quick_open_candidates();
+    let shown = candidates.len();
+
+    let area = f.area();
+    let width = 56u16.min(area.width.saturating_sub(4));
+    // border + input + spacer + (label + recents) + spacer + hint
+    let list_h = if shown > 0 { shown as u16 + 1 } else { 0 };
+    let height = (6 + list_h).min(area.height.saturating_sub(2));
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 3;
+    let modal_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(ACCENT))
+        .title(Span::styled(
+            " Go to Issue ",
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(vec![
+        Span::styled("  Key or URL: ", Style::default().fg(Color::Rgb(140, 200, 255))),
+        Span::styled(
+            visible_input(
+                &app.quick_open_input,
+                app.cursor_pos,
+                (inner.width as usize).saturating_sub(15),
+            ),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    if shown > 0 {
+        lines.push(Line::from(Span::styled(
+            "  Recently viewed",
+            Style::default().fg(DIM),
+        )));
+        for (i, recent) in candidates.iter().enumerate() {
+            let selected = app.quick_open_selected == Some(i);
+            let marker = if selected { "▶ " } else { "  " };
+            let fg = if selected { Color::White } else { Color::Rgb(180, 180, 180) };
+            let pin = if app.is_pinned(&recent.key) { "★ " } else { "" };
+            let summary_w = (inner.width as usize)
+                .saturating_sub(6 + pin.chars().count() + recent.key.chars().count());
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {marker}"), Style::default().fg(ACCENT)),
+                Span::styled(pin, Style::default().fg(PIN_COLOR)),
+                Span::styled(
+                    recent.key.clone(),
+                    Style::default().fg(fg).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    format!(" {}", truncate(&recent.summary, summary_w)),
+                    Style::default().fg(DIM),
+                ),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        "  Enter:Open  ↑↓:Recently viewed  Esc:Cancel",
+        Style::default().fg(Color::Rgb(100, 100, 120)),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Label for the issues a bulk action applies to: the key, or a count.
+fn bulk_target_label(app: &App) -> String {
+    match app.target_keys().as_slice() {
+        [key] => key.clone(),
+        keys => format!("{} issues", keys.len()),
+    }
+}
+
+// ── Assign modal ─────────────────────────────────────────────
+
+fn draw_assign_modal(f: &mut Frame, app: &App) {
+    const MAX_CANDIDATES: usize = 8;
+    let shown = app.assign_candidates.len().min(MAX_CANDIDATES);
+
+    let area = f.area();
+    let width = 56u16.min(area.width.saturating_sub(4));
+    // border + input + spacer + candidates + spacer + hint
+    let list_h = if shown > 0 { shown as u16 + 1 } else { 0 };
+    let height = (6 + list_h).min(area.height.saturating_sub(2));
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 3;
+    let modal_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(ACCENT))
+        .title(Span::styled(
+            format!(" Assign {} ", bulk_target_label(app)),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let mut lines: Vec<Line> = Vec::new();
+    lines.push(Line::from(vec![
+        Span::styled("  Assignee: ", Style::default().fg(Color::Rgb(140, 200, 255))),
+        Span::styled(
+            visible_input(
+                &app.assign_input,
+                app.cursor_pos,
+                (inner.width as usize).saturating_sub(13),
+            ),
+            Style::default()
+                .fg(Color::White)
+                .add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    lines.push(Line::from(""));
+
+    if shown > 0 {
+        for (i, user) in app.assign_candidates.iter().take(MAX_CANDIDATES).enumerate() {
+            let selected = i == app.assign_selected;
+            let marker = if selected { "▶ " } else { "  " };
+            let fg = if selected { Color::White } else { Color::Rgb(180, 180, 180) };
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {marker}"), Style::default().fg(ACCENT)),
+                Span::styled(user.display_name.clone(), Style::default().fg(fg)),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
+    lines.push(Line::from(Span::styled(
+        "  Type a name  ↑↓:Navigate  Enter:Assign  Esc:Cancel",
+        Style::default().fg(Color::Rgb(100, 100, 120)),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+// ── Add label modal ──────────────────────────────────────────
+
+fn draw_label_modal(f: &mut Frame, app: &App) {
+    let area = f.area();
+    let width = 56u16.min(area.width.saturating_sub(4));
+    let height = 5u16;
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 3;
+    let modal_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(ACCENT))
+        .title(Span::styled(
+            format!(" Add Label to {} ", bulk_target_label(app)),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let lines = vec![
+        Line::from(vec![
+            Span::styled("  Label: ", Style::default().fg(Color::Rgb(140, 200, 255))),
+            Span::styled(
+                visible_input(
+                    &app.label_input,
+                    app.cursor_pos,
+                    (inner.width as usize).saturating_sub(10),
+                ),
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::from(""),
+        Line::from(Span::styled(
+            "  Enter:Add  Esc:Cancel",
+            Style::default().fg(Color::Rgb(100, 100, 120)),
+        )),
+    ];
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
 // ── Highlight picker modal ───────────────────────────────────
 
 fn draw_highlight_picker_modal(f: &mut Frame, app: &App) {
     let current = app.current_highlight();
-    // Options: Yellow, Green, Remove (only if currently highlighted)
-    let has_highlight = current.is_some();
+    // Options: Yellow, Green, Remove (only if a targeted issue is highlighted)
+    let has_highlight = app.highlight_removable();
     let option_count = if has_highlight { HIGHLIGHT_OPTIONS.len() + 1 } else { HIGHLIGHT_OPTIONS.len() };
     let height = (option_count as u16) + 4; // border + title + options + hints
 
@@ -654,11 +1127,11 @@ fn draw_highlight_picker_modal(f: &mut Frame, app: &App) {
 // ── Sort picker modal ────────────────────────────────────────
 
 fn draw_sort_picker_modal(f: &mut Frame, app: &App) {
-    let options = SortCriteria::ALL;
+    let options = app.sort_options();
     let height = (options.len() as u16) + 4; // border + options + hint
 
     let area = f.area();
-    let width = 40u16.min(area.width.saturating_sub(4));
+    let width = 56u16.min(area.width.saturating_sub(4));
     let x = (area.width.saturating_sub(width)) / 2;
     let y = (area.height.saturating_sub(height)) / 2;
     let modal_area = Rect::new(x, y, width, height);
@@ -680,35 +1153,37 @@ fn draw_sort_picker_modal(f: &mut Frame, app: &App) {
 
     for (i, opt) in options.iter().enumerate() {
         let marker = if i == app.sort_selected { "▶ " } else { "  " };
-        let is_active = *opt == app.sort_criteria;
-        let label = if is_active {
-            format!("{} (active)", opt.label())
-        } else {
-            opt.label().to_string()
-        };
         let fg = if i == app.sort_selected {
             Color::White
         } else {
             Color::Rgb(180, 180, 180)
         };
+        // Position among the sort keys, with the key's direction
+        let key = app.sort_keys.iter().enumerate().find(|(_, k)| k.criteria == *opt);
+        let (rank, direction) = match key {
+            Some((n, k)) => (format!("{} ", n + 1), opt.direction_label(k.descending)),
+            None => ("  ".to_string(), ""),
+        };
         lines.push(Line::from(vec![
             Span::styled(marker, Style::default().fg(fg)),
-            Span::styled(label, Style::default().fg(fg)),
+            Span::styled(rank, Style::default().fg(ACCENT).add_modifier(Modifier::BOLD)),
+            Span::styled(format!("{:<24}", opt.label()), Style::default().fg(fg)),
+            Span::styled(direction, Style::default().fg(DIM)),
         ]));
     }
 
     lines.push(Line::from(Span::styled(
-        " Enter:Select  Esc:Cancel",
+        " Enter:Only  Space:Then by  r:Reverse  Esc:Close",
         Style::default().fg(Color::Rgb(100, 100, 120)),
     )));
 
     f.render_widget(Paragraph::new(lines), inner);
 }
 
-// ── Column picker modal ─────────────────────────────────────
+// ── Group picker modal ───────────────────────────────────────
 
-fn draw_column_picker_modal(f: &mut Frame, app: &App) {
-    let options = Column::ALL;
+fn draw_group_picker_modal(f: &mut Frame, app: &App) {
+    let options = GroupBy::ALL;
     let height = (options.len() as u16) + 4;
 
     let area = f.area();
@@ -723,7 +1198,7 @@ fn draw_column_picker_modal(f: &mut Frame, app: &App) {
         .borders(Borders::ALL)
         .border_style(Style::default().fg(ACCENT))
         .title(Span::styled(
-            " Columns ",
+            " Group by ",
             Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
         ));
 
@@ -732,35 +1207,147 @@ fn draw_column_picker_modal(f: &mut Frame, app: &App) {
 
     let mut lines: Vec<Line> = Vec::new();
 
-    for (i, col) in options.iter().enumerate() {
-        let selected = i == app.column_picker_selected;
-        let marker = if selected { "▶ " } else { "  " };
-        let visible = app.is_column_visible(*col);
-
-        let (icon, icon_color) = if visible {
-            ("✓", Color::Green)
+    for (i, opt) in options.iter().enumerate() {
+        let marker = if i == app.group_selected { "▶ " } else { "  " };
+        let label = if *opt == app.group_by {
+            format!("{} (active)", opt.label())
         } else {
-            ("✕", Color::Red)
+            opt.label().to_string()
         };
-
-        let label = col.label();
-        let note = if *col == Column::Assignee && !app.show_all_parents {
-            " (needs tree mode)"
+        let fg = if i == app.group_selected {
+            Color::White
         } else {
-            ""
+            Color::Rgb(180, 180, 180)
         };
-
-        let fg = if selected { Color::White } else { Color::Rgb(180, 180, 180) };
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(fg)),
+            Span::styled(label, Style::default().fg(fg)),
+        ]));
+    }
+
+    lines.push(Line::from(Span::styled(
+        " Enter:Select  Esc:Cancel",
+        Style::default().fg(Color::Rgb(100, 100, 120)),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+// ── Sprint picker modal ──────────────────────────────────────
+
+fn draw_sprint_picker_modal(f: &mut Frame, app: &App) {
+    let options = app.sprint_options();
+    let height = (options.len() as u16) + 5;
+
+    let area = f.area();
+    let width = 48u16.min(area.width.saturating_sub(4));
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 2;
+    let modal_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(ACCENT))
+        .title(Span::styled(
+            format!(" Move {} to sprint ", bulk_target_label(app)),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let mut lines: Vec<Line> = Vec::new();
+
+    for (i, sprint) in options.iter().enumerate() {
+        let marker = if i == app.sprint_picker_selected { "▶ " } else { "  " };
+        let which = if sprint.state == "active" { "active" } else { "next" };
+        let fg = if i == app.sprint_picker_selected {
+            Color::White
+        } else {
+            Color::Rgb(180, 180, 180)
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(fg)),
+            Span::styled(sprint.name.clone(), Style::default().fg(fg)),
+            Span::styled(format!(" ({which})"), Style::default().fg(DIM)),
+        ]));
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        " Enter:Move  Esc:Cancel",
+        Style::default().fg(Color::Rgb(100, 100, 120)),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
+// ── Column picker modal ─────────────────────────────────────
+
+fn draw_column_picker_modal(f: &mut Frame, app: &App) {
+    let options = app.column_picker_entries();
+    let height = (options.len() as u16) + 4;
+
+    let area = f.area();
+    let width = 52u16.min(area.width.saturating_sub(4));
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 2;
+    let modal_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(ACCENT))
+        .title(Span::styled(
+            " Columns ",
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let mut lines: Vec<Line> = Vec::new();
+
+    for (i, col) in options.iter().enumerate() {
+        let selected = i == app.column_picker_selected;
+        let marker = if selected { "▶ " } else { "  " };
+        let visible = app.is_column_visible(col);
+
+        let (icon, icon_color) = if visible {
+            ("✓", Color::Green)
+        } else {
+            ("✕", Color::Red)
+        };
+
+        let label = col.label();
+        let note = if *col == Column::Assignee && !app.show_all_parents {
+            " (needs tree mode)"
+        } else if *col == Column::Sprint && app.sprint.is_none() {
+            " (needs active sprint)"
+        } else if *col == Column::StoryPoints && app.config.story_points_field.is_none() {
+            " (set story points field in setup)"
+        } else {
+            ""
+        };
+
+        let fg = if selected { Color::White } else { Color::Rgb(180, 180, 180) };
         lines.push(Line::from(vec![
             Span::styled(marker, Style::default().fg(fg)),
             Span::styled(format!("{icon} "), Style::default().fg(icon_color)),
-            Span::styled(label.to_string(), Style::default().fg(fg)),
+            Span::styled(format!("{label:<12}"), Style::default().fg(fg)),
+            Span::styled(
+                format!("{:>3}", app.column_width(col)),
+                Style::default().fg(Color::Rgb(100, 100, 120)),
+            ),
             Span::styled(note.to_string(), Style::default().fg(Color::DarkGray)),
         ]));
     }
 
     lines.push(Line::from(Span::styled(
-        " ↑↓:Navigate  Space:Toggle  Esc:Close",
+        " ↑↓:Nav  Space:Toggle  J/K:Move  +/-:Width  Esc",
         Style::default().fg(Color::Rgb(100, 100, 120)),
     )));
 
@@ -769,21 +1356,83 @@ fn draw_column_picker_modal(f: &mut Frame, app: &App) {
 
 // ── Confirm transition modal ─────────────────────────────────
 
+/// Label for the issues a transition applies to: the key, or a count for bulk.
+fn transition_target_label(app: &App) -> String {
+    match app.transition_targets.as_slice() {
+        [(key, _)] => key.clone(),
+        targets => format!("{} issues", targets.len()),
+    }
+}
+
+// ── Transition picker modal (issue list) ────────────────────
+
+fn draw_transition_picker_modal(f: &mut Frame, app: &App) {
+    let height = (app.transitions.len() as u16 + 4).min(f.area().height.saturating_sub(2));
+
+    let area = f.area();
+    let width = 48u16.min(area.width.saturating_sub(4));
+    let x = (area.width.saturating_sub(width)) / 2;
+    let y = (area.height.saturating_sub(height)) / 2;
+    let modal_area = Rect::new(x, y, width, height);
+
+    f.render_widget(Clear, modal_area);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(ACCENT))
+        .title(Span::styled(
+            format!(" Transition {} ", transition_target_label(app)),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+
+    let inner = block.inner(modal_area);
+    f.render_widget(block, modal_area);
+
+    let mut lines: Vec<Line> = Vec::new();
+    for (i, t) in app.transitions.iter().enumerate() {
+        let selected = i == app.transition_selected;
+        let marker = if selected { "▶ " } else { "  " };
+        let fg = if selected {
+            Color::White
+        } else {
+            Color::Rgb(180, 180, 180)
+        };
+        let mut spans = vec![
+            Span::styled(marker, Style::default().fg(fg)),
+            Span::styled(t.name.clone(), Style::default().fg(fg)),
+        ];
+        if t.name != t.to_status {
+            spans.push(Span::styled(
+                format!(" \u{2192} {}", t.to_status),
+                Style::default().fg(DIM),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+
+    lines.push(Line::from(Span::styled(
+        " Enter:Select  Esc:Cancel",
+        Style::default().fg(Color::Rgb(100, 100, 120)),
+    )));
+
+    f.render_widget(Paragraph::new(lines), inner);
+}
+
 fn draw_confirm_transition_modal(f: &mut Frame, app: &App) {
-    let key = app
-        .detail
-        .as_ref()
-        .map(|d| d.key.as_str())
-        .unwrap_or("");
-    let target = app
-        .transitions
-        .get(app.transition_selected)
-        .map(|t| t.name.as_str())
-        .unwrap_or("");
+    let key = transition_target_label(app);
+    let transition = match app.transitions.get(app.transition_selected) {
+        Some(t) => t,
+        None => return,
+    };
+    let form = match &app.transition_form {
+        Some(form) => form,
+        None => return,
+    };
 
     let area = f.area();
-    let width = 52u16.min(area.width.saturating_sub(4));
-    let height = 7u16;
+    let width = 72u16.min(area.width.saturating_sub(4));
+    // pad + target + pad + fields + comment + pad + status + help + borders
+    let height = (transition.fields.len() as u16 + 9).min(area.height.saturating_sub(2));
     let x = (area.width.saturating_sub(width)) / 2;
     let y = (area.height.saturating_sub(height)) / 2;
     let modal_area = Rect::new(x, y, width, height);
@@ -801,144 +1450,129 @@ fn draw_confirm_transition_modal(f: &mut Frame, app: &App) {
     let inner = block.inner(modal_area);
     f.render_widget(block, modal_area);
 
-    let lines = vec![
+    let label_w = transition
+        .fields
+        .iter()
+        .map(|field| field.name.chars().count() + 1)
+        .chain(std::iter::once("Comment".len()))
+        .max()
+        .unwrap_or(0);
+    let value_w = (inner.width as usize).saturating_sub(label_w + 6);
+
+    let mut lines = vec![
         Line::from(""),
         Line::from(vec![
             Span::styled("  ", Style::default()),
             Span::styled(
-                key.to_string(),
+                key,
                 Style::default()
                     .fg(ACCENT)
                     .add_modifier(Modifier::BOLD),
             ),
             Span::styled(" \u{2192} ", Style::default().fg(Color::White)),
             Span::styled(
-                target.to_string(),
+                transition.name.clone(),
                 Style::default()
                     .fg(Color::Green)
                     .add_modifier(Modifier::BOLD),
             ),
         ]),
         Line::from(""),
-        Line::from(Span::styled(
-            "  y/Enter:Confirm  n/Esc:Cancel",
-            Style::default().fg(Color::Rgb(100, 100, 120)),
-        )),
     ];
 
-    f.render_widget(Paragraph::new(lines), inner);
-}
-
-// ── PR list modal ────────────────────────────────────────────
-
-fn draw_pr_list_modal(f: &mut Frame, app: &App) {
-    let prs = &app.pr_list;
-    let pr_rows = prs.len() as u16;
-
-    let area = f.area();
-    // Wide as the detail modal, tall enough for all PRs + padding
-    let width = area.width.saturating_sub(6).min(120);
-    let inner_h = 1 + pr_rows.max(1) + 1 + 1 + 1; // top pad + rows + spacer + help + bot pad
-    let height = (inner_h + 2).min(area.height.saturating_sub(8));
-    let x = (area.width.saturating_sub(width)) / 2;
-    let y = (area.height.saturating_sub(height)) / 2;
-    let modal_area = Rect::new(x, y, width, height);
-
-    // GitHub dark mode palette
-    const GH_BG: Color       = Color::Rgb(13, 17, 23);   // #0d1117 canvas-default
-    const GH_BG_SEL: Color   = Color::Rgb(22, 27, 34);   // #161b22 canvas-overlay
-    const GH_BLUE: Color     = Color::Rgb(88, 166, 255);  // #58a6ff accent
-    const GH_TEXT: Color     = Color::Rgb(230, 237, 243); // #e6edf3 fg-default
-    const GH_MUTED: Color    = Color::Rgb(139, 148, 158); // #8b949e fg-muted
-    const GH_GREEN: Color    = Color::Rgb(63, 185, 80);   // #3fb950 open
-    const GH_PURPLE: Color   = Color::Rgb(137, 87, 229);  // #8957e5 merged
-    const GH_RED: Color      = Color::Rgb(248, 81, 73);   // #f85149 closed
-
-    f.render_widget(Clear, modal_area);
-
-    let block = Block::default()
-        .borders(Borders::ALL)
-        .border_style(Style::default().fg(GH_BLUE))
-        .style(Style::default().bg(GH_BG))
-        .title(Line::from(vec![
-            Span::styled("  ", Style::default().bg(GH_BG)),
-            Span::styled("⎇", Style::default().fg(GH_BLUE).bg(GH_BG)),
-            Span::styled(
-                "  Pull Requests  ",
-                Style::default().fg(GH_TEXT).bg(GH_BG).add_modifier(Modifier::BOLD),
+    let label_style = |focused: bool| {
+        if focused {
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(DIM)
+        }
+    };
+    let marker = |focused: bool| if focused { "\u{25b8} " } else { "  " };
+
+    for (i, field) in transition.fields.iter().enumerate() {
+        let focused = form.focused == i;
+        let label = format!("{:<label_w$}", format!("{}*", field.name));
+        let value = match field.kind {
+            TransitionFieldKind::Option | TransitionFieldKind::MultiOption => {
+                let name = form.options[i]
+                    .and_then(|o| field.allowed_values.get(o))
+                    .map(|o| o.name.as_str())
+                    .unwrap_or("(choose)");
+                if focused {
+                    Span::styled(
+                        format!("\u{25c0} {name} \u{25b6}"),
+                        Style::default().fg(Color::White).add_modifier(Modifier::BOLD),
+                    )
+                } else {
+                    Span::styled(name.to_string(), Style::default().fg(Color::White))
+                }
+            }
+            TransitionFieldKind::Unsupported => Span::styled(
+                "(set in browser)".to_string(),
+                Style::default().fg(Color::Red),
             ),
+            _ if focused => Span::styled(
+                visible_input(&form.texts[i], app.cursor_pos, value_w),
+                Style::default().fg(Color::White),
+            ),
+            _ => Span::styled(form.texts[i].clone(), Style::default().fg(Color::White)),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(marker(focused), Style::default().fg(ACCENT)),
+            Span::styled(label, label_style(focused)),
+            Span::raw("  "),
+            value,
         ]));
+    }
 
-    let inner = block.inner(modal_area);
-    f.render_widget(block, modal_area);
-
-    let pad = "  ";
-    let mut lines: Vec<Line> = Vec::new();
-    lines.push(Line::from("")); // top padding
-
-    if prs.is_empty() {
-        lines.push(Line::from(Span::styled(
-            format!("{pad}No pull requests found"),
-            Style::default().fg(GH_MUTED).bg(GH_BG),
-        )));
+    let focused = form.focused == transition.fields.len();
+    let comment = if focused {
+        visible_input(&form.comment, app.cursor_pos, value_w)
+    } else if form.comment.is_empty() {
+        "(optional)".to_string()
     } else {
-        for (i, pr) in prs.iter().enumerate() {
-            let selected = i == app.pr_list_selected;
-            let marker = if selected { "▶ " } else { "  " };
-            let (state_label, state_color) = match pr.state.as_str() {
-                "open"   => ("● OPEN",   GH_GREEN),
-                "closed" => ("✕ CLOSED", GH_RED),
-                _        => ("⎇ MERGED", GH_PURPLE),
-            };
-            let bg = if selected { GH_BG_SEL } else { GH_BG };
-            let title_fg = if selected { GH_TEXT } else { Color::Rgb(200, 207, 216) };
-            // Reserve space for: pad + marker + "#NNNNN " + "  STATE_LABEL" + "  @user"
-            let badge_w  = state_label.len() + 3;
-            let user_w   = pr.user.len() + 4;
-            let prefix_w = pad.len() + 2 + 7;
-            let max_title = (inner.width as usize).saturating_sub(prefix_w + badge_w + user_w);
-            let title: String = if pr.title.chars().count() > max_title {
-                pr.title.chars().take(max_title.saturating_sub(1)).collect::<String>() + "…"
+        form.comment.clone()
+    };
+    lines.push(Line::from(vec![
+        Span::styled(marker(focused), Style::default().fg(ACCENT)),
+        Span::styled(format!("{:<label_w$}", "Comment"), label_style(focused)),
+        Span::raw("  "),
+        Span::styled(
+            comment,
+            Style::default().fg(if focused || !form.comment.is_empty() {
+                Color::White
             } else {
-                pr.title.clone()
-            };
-            lines.push(Line::from(vec![
-                Span::styled(
-                    format!("{pad}{marker}"),
-                    Style::default().fg(GH_BLUE).bg(bg),
-                ),
-                Span::styled(
-                    format!("#{:<5} ", pr.number),
-                    Style::default().fg(GH_BLUE).bg(bg).add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    title,
-                    Style::default().fg(title_fg).bg(bg),
-                ),
-                Span::styled(
-                    format!("  {}", state_label),
-                    Style::default().fg(state_color).bg(bg).add_modifier(Modifier::BOLD),
-                ),
-                Span::styled(
-                    format!("  @{}", pr.user),
-                    Style::default().fg(GH_MUTED).bg(bg),
-                ),
-            ]));
-        }
-    }
+                DIM
+            }),
+        ),
+    ]));
 
-    lines.push(Line::from("")); // spacer
-    let help = if prs.is_empty() {
-        format!("{pad}Esc:Close")
+    lines.push(Line::from(""));
+    let status_msg = if app.mode == Mode::ConfirmTransition {
+        &app.status_msg
+    } else {
+        &app.detail_status_msg
+    };
+    let status = if status_msg.is_empty() {
+        Line::from("")
     } else {
-        format!("{pad}↑↓:Navigate  Enter:Open in browser  Esc:Close")
+        let color = if status_msg.starts_with("Error") {
+            Color::Red
+        } else {
+            Color::Yellow
+        };
+        Line::from(Span::styled(
+            format!("  {status_msg}"),
+            Style::default().fg(color),
+        ))
     };
+    lines.push(status);
     lines.push(Line::from(Span::styled(
-        help,
-        Style::default().fg(GH_MUTED).bg(GH_BG),
+        "  Tab:Next field  \u{2190}\u{2192}:Change option  Enter:Confirm  Esc:Cancel",
+        Style::default().fg(Color::Rgb(100, 100, 120)),
     )));
 
-    f.render_widget(Paragraph::new(lines).style(Style::default().bg(GH_BG)), inner);
+    f.render_widget(Paragraph::new(lines), inner);
 }
 
 // ── Filter modal ────────────────────────────────────────────
@@ -1086,25 +1720,38 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
     }
 
     let (icon, icon_color) = issue_type_icon(&detail.issue_type);
-    let priority = app.rows.get(app.selected)
-        .map(|r| r.issue.priority.as_str())
-        .unwrap_or("");
-    let (pflag, pname, pflag_color) = priority_flag(priority);
+    let (pflag, pname, pflag_color) = priority_flag(&detail.priority);
+    let mut title_spans = vec![
+        Span::styled(" ", Style::default()),
+        Span::styled(icon.to_string(), Style::default().fg(icon_color)),
+        Span::styled(
+            format!(" {} ", detail.key),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("{} {} ", pflag, pname),
+            Style::default().fg(pflag_color),
+        ),
+    ];
+    let (watch_icon, watch_color) = if detail.is_watching {
+        ("◉", Color::Rgb(140, 200, 255))
+    } else {
+        ("○", DIM)
+    };
+    title_spans.push(Span::styled(
+        format!("{watch_icon} {} ", detail.watch_count),
+        Style::default().fg(watch_color),
+    ));
+    if !app.detail_back.is_empty() || !app.detail_forward.is_empty() {
+        title_spans.push(Span::styled(
+            format!("◀ {}  {} ▶ ", app.detail_back.len(), app.detail_forward.len()),
+            Style::default().fg(DIM),
+        ));
+    }
     let block = Block::default()
         .borders(Borders::ALL)
         .border_style(Style::default().fg(ACCENT))
-        .title(Line::from(vec![
-            Span::styled(" ", Style::default()),
-            Span::styled(icon.to_string(), Style::default().fg(icon_color)),
-            Span::styled(
-                format!(" {} ", detail.key),
-                Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                format!("{} {} ", pflag, pname),
-                Style::default().fg(pflag_color),
-            ),
-        ]));
+        .title(Line::from(title_spans));
 
     let inner = block.inner(modal_area);
     f.render_widget(block, modal_area);
@@ -1114,6 +1761,10 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
     let editing_summary = app.mode == Mode::DetailEditingSummary;
     let confirm_deleting = app.mode == Mode::DetailConfirmDelete;
     let picking_transition = app.mode == Mode::DetailTransition;
+    let picking_related = app.mode == Mode::DetailRelated;
+    let listing_watchers = app.mode == Mode::DetailWatchers;
+    let adding_watcher = app.mode == Mode::DetailAddingWatcher;
+    let finding = app.mode == Mode::DetailSearching || !app.detail_search_input.is_empty();
     let mention_count = app
         .mention
         .as_ref()
@@ -1130,12 +1781,36 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
         2
     } else if picking_transition {
         (app.transitions.len() as u16 + 3).min(inner.height / 2)
+    } else if picking_related {
+        (detail.related.len() as u16 + 3).min(inner.height / 2)
+    } else if listing_watchers {
+        let status_row = if app.detail_status_msg.is_empty() { 0 } else { 1 };
+        (app.watchers.len().max(1) as u16 + 3).min(inner.height / 2) + status_row
+    } else if adding_watcher {
+        4 + app.watcher_candidates.len() as u16
     } else {
-        1 + if !app.detail_status_msg.is_empty() { 1 } else { 0 }
+        1 + if !app.detail_status_msg.is_empty() { 1 } else { 0 } + if finding { 1 } else { 0 }
     };
 
-    let content_height = inner.height.saturating_sub(bottom_reserve);
-    let content_area = Rect::new(inner.x, inner.y, inner.width, content_height);
+    // Parent breadcrumb, tab bar and spacer above the scrolling content
+    let crumb_h: u16 = if detail.ancestors.is_empty() { 0 } else { 1 };
+    if crumb_h > 0 {
+        let crumb_area = Rect::new(inner.x, inner.y, inner.width, 1);
+        f.render_widget(Paragraph::new(breadcrumb_line(detail, inner.width as usize)), crumb_area);
+    }
+    let fields_h: u16 = if detail.custom.is_empty() { 0 } else { 1 };
+    if fields_h > 0 {
+        let fields_area = Rect::new(inner.x, inner.y + crumb_h, inner.width, 1);
+        f.render_widget(
+            Paragraph::new(custom_fields_line(detail, inner.width as usize)),
+            fields_area,
+        );
+    }
+    let tab_bar_area =
+        Rect::new(inner.x, inner.y + crumb_h + fields_h, inner.width, 1.min(inner.height));
+    let header_h = crumb_h + fields_h + 2;
+    let content_height = inner.height.saturating_sub(bottom_reserve + header_h);
+    let content_area = Rect::new(inner.x, inner.y + header_h, inner.width, content_height);
     let bottom_area = Rect::new(
         inner.x,
         inner.y + content_height,
@@ -1145,121 +1820,160 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
 
     let inner_w = inner.width as usize;
 
-    // Cache check: rebuild content only when version, selection, or width changes
-    let current_version = app.detail_content_version.get();
-    let current_selected = app.detail_comment_selected;
-    let current_width = inner.width;
+    let prs = app.detail_prs();
+    let pr_count = if app.pr_list_key.is_some() && !app.prs_stale() {
+        Some(prs.len())
+    } else {
+        None
+    };
+    let mut tab_spans = Vec::new();
+    for (i, tab) in DetailTab::ALL.iter().enumerate() {
+        let count = match tab {
+            DetailTab::Description => None,
+            DetailTab::Comments => Some(detail.comments.len()),
+            DetailTab::History => Some(detail.history.len()),
+            DetailTab::Links => Some(detail.links().count()),
+            DetailTab::Subtasks => Some(detail.subtasks().count()),
+            DetailTab::PRs => pr_count,
+        };
+        let label = match count {
+            Some(n) => format!("{} {} ({n})", i + 1, tab.label()),
+            None => format!("{} {}", i + 1, tab.label()),
+        };
+        let style = if *tab == app.detail_tab {
+            Style::default()
+                .fg(Color::Black)
+                .bg(ACCENT)
+                .add_modifier(Modifier::BOLD)
+        } else if count == Some(0) {
+            Style::default().fg(Color::Rgb(70, 70, 85))
+        } else {
+            Style::default().fg(DIM)
+        };
+        tab_spans.push(Span::styled(format!(" {label} "), style));
+        tab_spans.push(Span::raw(" "));
+    }
+    f.render_widget(Paragraph::new(Line::from(tab_spans)), tab_bar_area);
+
+    // Cache check: rebuild content only when version, tab, selection, or width changes
+    let current_version = app.detail_content_version.get();
+    let current_tab = app.detail_tab;
+    let current_selected = if current_tab.is_list() {
+        Some(app.detail_item_selected)
+    } else {
+        app.detail_comment_selected
+    };
+    let current_width = inner.width;
 
-    let cache_valid = app.detail_render_cache.borrow().as_ref().map_or(false, |c| {
+    let cache_valid = app.detail_render_cache.borrow().as_ref().is_some_and(|c| {
         c.version == current_version
-            && c.selected_comment == current_selected
+            && c.tab == current_tab
+            && c.selected == current_selected
             && c.render_width == current_width
     });
 
     if !cache_valid {
         let mut lines: Vec<Line> = Vec::new();
-        let mut comment_offsets: Vec<usize> = Vec::new();
-
-        // Summary
-        for sub in word_wrap(&detail.summary, inner_w) {
-            lines.push(Line::from(Span::styled(
-                sub,
-                Style::default()
-                    .fg(Color::White)
-                    .add_modifier(Modifier::BOLD),
-            )));
-        }
-        lines.push(Line::from(""));
-
-        // Description separator
-        let rule_w = inner_w.min(80);
-        let desc_label = "── Description ";
-        let desc_rule_len = rule_w.saturating_sub(desc_label.len());
-        lines.push(Line::from(Span::styled(
-            format!("{}{}", desc_label, "─".repeat(desc_rule_len)),
-            Style::default().fg(ACCENT),
-        )));
-        lines.push(Line::from(""));
-
-        lines.extend(markdown_to_lines(&detail.description, inner_w));
-
-        lines.push(Line::from(""));
-
-        // Comments separator
-        let comment_count = detail.comments.len();
-        let comments_label = format!("── Comments ({comment_count}) ");
-        let comments_rule_len = rule_w.saturating_sub(comments_label.len());
-        lines.push(Line::from(Span::styled(
-            format!("{}{}", comments_label, "─".repeat(comments_rule_len)),
-            Style::default().fg(ACCENT),
-        )));
-
-        if detail.comments.is_empty() {
-            lines.push(Line::from(""));
-            lines.push(Line::from(Span::styled(
-                "  No comments",
-                Style::default().fg(Color::DarkGray),
-            )));
-        } else {
-            let comment_w = inner_w.saturating_sub(4);
-            for (i, comment) in detail.comments.iter().enumerate() {
-                let is_selected = current_selected == Some(i);
-
-                comment_offsets.push(lines.len());
-                lines.push(Line::from(""));
-
-                let marker = if is_selected { "▶ " } else { "  " };
-                let num_label = format!("#{}", i + 1);
+        let mut item_offsets: Vec<usize> = Vec::new();
 
-                lines.push(Line::from(vec![
-                    Span::styled(marker.to_string(), Style::default().fg(ACCENT)),
-                    Span::styled(
-                        num_label,
-                        Style::default()
-                            .fg(Color::Rgb(180, 180, 200))
-                            .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled("  ", Style::default()),
-                    Span::styled(
-                        comment.author.clone(),
+        match current_tab {
+            DetailTab::Description => {
+                // Summary
+                for sub in word_wrap(&detail.summary, inner_w) {
+                    lines.push(Line::from(Span::styled(
+                        sub,
                         Style::default()
-                            .fg(Color::Rgb(140, 200, 255))
+                            .fg(Color::White)
                             .add_modifier(Modifier::BOLD),
-                    ),
-                    Span::styled(
-                        format!("  {}", comment.created),
+                    )));
+                }
+                lines.push(Line::from(""));
+                lines.extend(markdown_to_lines(&detail.description, inner_w));
+            }
+            DetailTab::Comments => {
+                if detail.comments.is_empty() {
+                    lines.push(Line::from(Span::styled(
+                        "  No comments",
                         Style::default().fg(Color::DarkGray),
-                    ),
-                ]));
-
-                for md_line in markdown_to_lines(&comment.body, comment_w) {
-                    let mut prefixed: Vec<Span> =
-                        vec![Span::styled("    ".to_string(), Style::default())];
-                    if is_selected {
-                        for span in md_line.spans {
-                            let mut style = span.style;
-                            if style.fg.is_none()
-                                || style.fg == Some(Color::Rgb(200, 200, 210))
-                            {
-                                style = style.fg(Color::White);
+                    )));
+                } else {
+                    let comment_w = inner_w.saturating_sub(4);
+                    for (i, comment) in detail.comments.iter().enumerate() {
+                        let is_selected = current_selected == Some(i);
+
+                        if i > 0 {
+                            lines.push(Line::from(""));
+                        }
+                        item_offsets.push(lines.len());
+
+                        let marker = if is_selected { "▶ " } else { "  " };
+                        let num_label = format!("#{}", i + 1);
+
+                        lines.push(Line::from(vec![
+                            Span::styled(marker.to_string(), Style::default().fg(ACCENT)),
+                            Span::styled(
+                                num_label,
+                                Style::default()
+                                    .fg(Color::Rgb(180, 180, 200))
+                                    .add_modifier(Modifier::BOLD),
+                            ),
+                            Span::styled("  ", Style::default()),
+                            Span::styled(
+                                comment.author.clone(),
+                                Style::default()
+                                    .fg(Color::Rgb(140, 200, 255))
+                                    .add_modifier(Modifier::BOLD),
+                            ),
+                            Span::styled(
+                                format!("  {}", comment.created),
+                                Style::default().fg(Color::DarkGray),
+                            ),
+                        ]));
+
+                        for md_line in markdown_to_lines(&comment.body, comment_w) {
+                            let mut prefixed: Vec<Span> =
+                                vec![Span::styled("    ".to_string(), Style::default())];
+                            if is_selected {
+                                for span in md_line.spans {
+                                    let mut style = span.style;
+                                    if style.fg.is_none()
+                                        || style.fg == Some(Color::Rgb(200, 200, 210))
+                                    {
+                                        style = style.fg(Color::White);
+                                    }
+                                    prefixed.push(Span::styled(span.content.into_owned(), style));
+                                }
+                            } else {
+                                prefixed.extend(md_line.spans.into_iter().map(|s| {
+                                    Span::styled(s.content.into_owned(), s.style)
+                                }));
                             }
-                            prefixed.push(Span::styled(span.content.into_owned(), style));
+                            lines.push(Line::from(prefixed));
                         }
-                    } else {
-                        prefixed.extend(md_line.spans.into_iter().map(|s| {
-                            Span::styled(s.content.into_owned(), s.style)
-                        }));
-                    }
-                    lines.push(Line::from(prefixed));
-                }
 
-                if i + 1 < detail.comments.len() {
-                    lines.push(Line::from(Span::styled(
-                        "  ── ── ── ──",
-                        Style::default().fg(Color::Rgb(60, 60, 80)),
-                    )));
+                        if i + 1 < detail.comments.len() {
+                            lines.push(Line::from(Span::styled(
+                                "  ── ── ── ──",
+                                Style::default().fg(Color::Rgb(60, 60, 80)),
+                            )));
+                        }
+                    }
                 }
             }
+            DetailTab::History => lines = history_lines(detail, inner_w, &app.status_categories),
+            DetailTab::Links => {
+                let links: Vec<_> = detail.links().collect();
+                (lines, item_offsets) =
+                    related_lines(&links, current_selected, inner_w, "No linked issues");
+            }
+            DetailTab::Subtasks => {
+                let subtasks: Vec<_> = detail.subtasks().collect();
+                (lines, item_offsets) =
+                    related_lines(&subtasks, current_selected, inner_w, "No subtasks");
+            }
+            DetailTab::PRs => {
+                (lines, item_offsets) = pr_lines(prs, current_selected, inner_w);
+            }
         }
 
         lines.push(Line::from(""));
@@ -1280,11 +1994,12 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
 
         *app.detail_render_cache.borrow_mut() = Some(DetailRenderCache {
             version: current_version,
-            selected_comment: current_selected,
+            tab: current_tab,
+            selected: current_selected,
             render_width: current_width,
             lines,
             link_map,
-            comment_offsets,
+            item_offsets,
         });
     }
 
@@ -1293,12 +2008,18 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
         let cache = app.detail_render_cache.borrow();
         let cache = cache.as_ref().unwrap();
         *app.detail_link_map.borrow_mut() = cache.link_map.clone();
-        *app.detail_comment_offsets.borrow_mut() = cache.comment_offsets.clone();
+        *app.detail_item_offsets.borrow_mut() = cache.item_offsets.clone();
         app.detail_content_y.set(content_area.y);
         app.detail_content_height.set(content_area.height);
 
         let total_lines = cache.lines.len();
-        let paragraph = Paragraph::new(cache.lines.clone())
+        let lines = if finding {
+            let matches = find_line_matches(&cache.lines, &app.detail_search_input);
+            highlight_line_matches(&cache.lines, &matches, app.detail_match_selected)
+        } else {
+            cache.lines.clone()
+        };
+        let paragraph = Paragraph::new(lines)
             .wrap(Wrap { trim: false })
             .scroll((app.detail_scroll, 0));
         f.render_widget(paragraph, content_area);
@@ -1453,69 +2174,674 @@ fn draw_detail_modal(f: &mut Frame, app: &App) {
                 "┌─ {label} {}",
                 "─".repeat(inner_w.saturating_sub(label.len() + 4))
             ),
-            Style::default().fg(Color::Rgb(100, 100, 140)),
-        )));
-
-        let current_status = app.detail.as_ref().map(|d| d.status.as_str()).unwrap_or("");
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+
+        let current_status = app.detail.as_ref().map(|d| d.status.as_str()).unwrap_or("");
+
+        for (i, t) in app.transitions.iter().enumerate() {
+            let selected = i == app.transition_selected;
+            let is_current = t.to_status == current_status;
+            let marker = if selected { "▶ " } else { "  " };
+            let mut spans = vec![
+                Span::styled(
+                    format!("│ {marker}"),
+                    Style::default().fg(Color::Rgb(100, 100, 140)),
+                ),
+                Span::styled(
+                    t.name.clone(),
+                    if selected {
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Rgb(180, 180, 200))
+                    },
+                ),
+            ];
+            if is_current {
+                spans.push(Span::styled(
+                    " (current)",
+                    Style::default().fg(Color::DarkGray),
+                ));
+            }
+            bottom_lines.push(Line::from(spans));
+        }
+
+        bottom_lines.push(Line::from(Span::styled(
+            format!("└{}", "─".repeat(inner_w.saturating_sub(1))),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+        bottom_lines.push(Line::from(Span::styled(
+            "↑↓:Navigate  Enter:Confirm  Esc:Cancel",
+            Style::default().fg(Color::Rgb(100, 100, 120)),
+        )));
+    } else if listing_watchers {
+        let label = format!("Watchers ({})", app.watchers.len());
+        bottom_lines.push(Line::from(Span::styled(
+            format!(
+                "┌─ {label} {}",
+                "─".repeat(inner_w.saturating_sub(label.chars().count() + 4))
+            ),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+
+        if app.watchers.is_empty() {
+            bottom_lines.push(Line::from(Span::styled(
+                "│   No watchers",
+                Style::default().fg(Color::DarkGray),
+            )));
+        }
+        let status_row = if app.detail_status_msg.is_empty() { 0 } else { 1 };
+        let visible = bottom_reserve.saturating_sub(3 + status_row) as usize;
+        let skip = (app.watchers_selected + 1).saturating_sub(visible);
+        for (i, w) in app.watchers.iter().enumerate().skip(skip).take(visible) {
+            let selected = i == app.watchers_selected;
+            let marker = if selected { "▶ " } else { "  " };
+            let mut spans = vec![
+                Span::styled(
+                    format!("│ {marker}"),
+                    Style::default().fg(Color::Rgb(100, 100, 140)),
+                ),
+                Span::styled(
+                    w.display_name.clone(),
+                    if selected {
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Rgb(180, 180, 200))
+                    },
+                ),
+            ];
+            if w.account_id == app.current_account_id {
+                spans.push(Span::styled(" (you)", Style::default().fg(Color::DarkGray)));
+            }
+            bottom_lines.push(Line::from(spans));
+        }
+
+        bottom_lines.push(Line::from(Span::styled(
+            format!("└{}", "─".repeat(inner_w.saturating_sub(1))),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+        if !app.detail_status_msg.is_empty() {
+            let fg = if app.detail_status_msg.starts_with("Error") {
+                Color::Rgb(220, 140, 140)
+            } else {
+                rainbow_color(app.detail_status_set_at.elapsed().as_millis(), 0.6, 0.7)
+            };
+            bottom_lines.push(Line::from(Span::styled(
+                app.detail_status_msg.clone(),
+                Style::default().fg(fg),
+            )));
+        }
+        bottom_lines.push(Line::from(Span::styled(
+            "↑↓:Navigate  a:Add watcher  x:Remove  w:Watch/Unwatch  Esc:Close",
+            Style::default().fg(Color::Rgb(100, 100, 120)),
+        )));
+    } else if adding_watcher {
+        let label = "Add watcher";
+        bottom_lines.push(Line::from(Span::styled(
+            format!(
+                "┌─ {label} {}",
+                "─".repeat(inner_w.saturating_sub(label.len() + 4))
+            ),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+
+        for (i, candidate) in app.watcher_candidates.iter().enumerate() {
+            let selected = i == app.watcher_candidate_selected;
+            let marker = if selected { "▶ " } else { "  " };
+            let name_style = if selected {
+                Style::default()
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(Color::Rgb(180, 180, 200))
+            };
+            bottom_lines.push(Line::from(vec![
+                Span::styled(
+                    format!("\u{2502} {marker}"),
+                    Style::default().fg(Color::Rgb(100, 100, 140)),
+                ),
+                Span::styled(candidate.display_name.clone(), name_style),
+            ]));
+        }
+
+        bottom_lines.push(Line::from(Span::styled(
+            format!(
+                "\u{2502} {}",
+                visible_input(
+                    &app.watcher_input,
+                    app.cursor_pos,
+                    inner_w.saturating_sub(2),
+                )
+            ),
+            Style::default().fg(Color::White),
+        )));
+        bottom_lines.push(Line::from(Span::styled(
+            format!("└{}", "─".repeat(inner_w.saturating_sub(1))),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+        bottom_lines.push(Line::from(Span::styled(
+            "Type a name  ↑↓:Navigate  Enter:Add  Esc:Cancel",
+            Style::default().fg(Color::Rgb(100, 100, 120)),
+        )));
+    } else if picking_related {
+        let label = "Open related issue";
+        bottom_lines.push(Line::from(Span::styled(
+            format!(
+                "┌─ {label} {}",
+                "─".repeat(inner_w.saturating_sub(label.len() + 4))
+            ),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+
+        // Keep the selection visible when the list is taller than the reserved area
+        let visible = bottom_reserve.saturating_sub(3) as usize;
+        let skip = (app.related_selected + 1).saturating_sub(visible);
+        for (i, r) in detail.related.iter().enumerate().skip(skip).take(visible) {
+            let selected = i == app.related_selected;
+            let marker = if selected { "▶ " } else { "  " };
+            let mut spans = vec![
+                Span::styled(
+                    format!("│ {marker}"),
+                    Style::default().fg(Color::Rgb(100, 100, 140)),
+                ),
+                Span::styled(
+                    format!("{:<14}", truncate(&r.relation, 14)),
+                    Style::default().fg(DIM),
+                ),
+                Span::styled(
+                    format!("{} ", r.key),
+                    Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(
+                    r.summary.clone(),
+                    if selected {
+                        Style::default()
+                            .fg(Color::White)
+                            .add_modifier(Modifier::BOLD)
+                    } else {
+                        Style::default().fg(Color::Rgb(180, 180, 200))
+                    },
+                ),
+            ];
+            if !r.status.is_empty() {
+                spans.push(Span::styled(
+                    format!("  {}", r.status),
+                    status_style(&r.status),
+                ));
+            }
+            bottom_lines.push(Line::from(spans));
+        }
+
+        bottom_lines.push(Line::from(Span::styled(
+            format!("└{}", "─".repeat(inner_w.saturating_sub(1))),
+            Style::default().fg(Color::Rgb(100, 100, 140)),
+        )));
+        bottom_lines.push(Line::from(Span::styled(
+            "↑↓:Navigate  Enter:Open here  Esc:Cancel",
+            Style::default().fg(Color::Rgb(100, 100, 120)),
+        )));
+    } else {
+        if finding {
+            let count = app.detail_search_matches().len();
+            let cursor = if app.mode == Mode::DetailSearching { "│" } else { "" };
+            let summary = if count == 0 {
+                "no matches".to_string()
+            } else {
+                format!("{}/{count} matches", app.detail_match_selected.min(count - 1) + 1)
+            };
+            bottom_lines.push(Line::from(vec![
+                Span::styled(
+                    "/",
+                    Style::default()
+                        .fg(Color::Rgb(255, 200, 60))
+                        .add_modifier(Modifier::BOLD),
+                ),
+                Span::styled(app.detail_search_input.clone(), Style::default().fg(Color::White)),
+                Span::styled(cursor, Style::default().fg(Color::Rgb(255, 200, 60))),
+                Span::styled(
+                    format!("  {summary}"),
+                    Style::default().fg(if count == 0 {
+                        Color::Rgb(220, 140, 140)
+                    } else {
+                        Color::Rgb(100, 100, 120)
+                    }),
+                ),
+            ]));
+        }
+        if !app.detail_status_msg.is_empty() {
+            let elapsed_ms = app.detail_status_set_at.elapsed().as_millis();
+            let is_error = app.detail_status_msg.starts_with("Error");
+            let fg = if is_error {
+                Color::Rgb(220, 140, 140)
+            } else {
+                rainbow_color(elapsed_ms, 0.6, 0.7)
+            };
+            bottom_lines.push(Line::from(Span::styled(
+                app.detail_status_msg.clone(),
+                Style::default().fg(fg),
+            )));
+        }
+        let step = if app.detail_search_input.is_empty() { "n/p:Comment" } else { "n/p:Match" };
+        bottom_lines.push(Line::from(Span::styled(
+            format!("Tab/1-6:Tabs  ↑↓:Scroll/Select  {step}  /:Find  y:Copy  l:Link  c:Add  e:Edit  x:Del  s:Summary  t:Transition  g:PRs  o:Related  u:Parent  P:Pin  w/W:Watch/Watchers  [/]:Back/Fwd  Enter:Open/Browser  Esc:Close"),
+            Style::default().fg(Color::Rgb(100, 100, 120)),
+        )));
+    }
+
+    f.render_widget(Paragraph::new(bottom_lines), bottom_area);
+}
+
+/// Copy of `lines` with search matches highlighted, the current one strongest.
+fn highlight_line_matches(
+    lines: &[Line<'static>],
+    matches: &[(usize, usize, usize)],
+    current: usize,
+) -> Vec<Line<'static>> {
+    let mut out = lines.to_vec();
+    for (mi, &(line_idx, start, len)) in matches.iter().enumerate() {
+        let bg = if mi == current {
+            Color::Rgb(255, 200, 60)
+        } else {
+            Color::Rgb(110, 90, 30)
+        };
+        let line = &mut out[line_idx];
+        let mut spans = Vec::new();
+        let mut pos = 0;
+        for span in line.spans.drain(..) {
+            let span_len = span.content.chars().count();
+            let (from, to) = (start.max(pos), (start + len).min(pos + span_len));
+            if from >= to {
+                spans.push(span);
+            } else {
+                let chars: Vec<char> = span.content.chars().collect();
+                let piece = |a: usize, b: usize| chars[a - pos..b - pos].iter().collect::<String>();
+                if from > pos {
+                    spans.push(Span::styled(piece(pos, from), span.style));
+                }
+                let mut hit = span.style.bg(bg);
+                if mi == current {
+                    hit = hit.fg(Color::Black).add_modifier(Modifier::BOLD);
+                }
+                spans.push(Span::styled(piece(from, to), hit));
+                if to < pos + span_len {
+                    spans.push(Span::styled(piece(to, pos + span_len), span.style));
+                }
+            }
+            pos += span_len;
+        }
+        line.spans = spans;
+    }
+    out
+}
+
+// ── Detail list tabs ─────────────────────────────────────────
+
+/// Links / subtasks tab: one row per related issue, with item offsets for scrolling.
+fn related_lines(
+    related: &[&RelatedIssue],
+    selected: Option<usize>,
+    width: usize,
+    empty: &str,
+) -> (Vec<Line<'static>>, Vec<usize>) {
+    if related.is_empty() {
+        return (
+            vec![Line::from(Span::styled(
+                format!("  {empty}"),
+                Style::default().fg(Color::DarkGray),
+            ))],
+            Vec::new(),
+        );
+    }
+
+    let relation_w = related
+        .iter()
+        .map(|r| r.relation.chars().count())
+        .max()
+        .unwrap_or(0)
+        .min(16);
+    let mut lines = Vec::new();
+    let mut offsets = Vec::new();
+    for (i, r) in related.iter().enumerate() {
+        let is_selected = selected == Some(i);
+        let marker = if is_selected { "▶ " } else { "  " };
+        let status_w = r.status.chars().count() + 2;
+        let summary_w = width.saturating_sub(2 + relation_w + 2 + r.key.len() + 1 + status_w);
+        let summary = if r.summary.is_empty() { "—" } else { r.summary.as_str() };
+        offsets.push(lines.len());
+        let mut spans = vec![
+            Span::styled(marker, Style::default().fg(ACCENT)),
+            Span::styled(
+                format!("{:<relation_w$}  ", truncate(&r.relation, relation_w)),
+                Style::default().fg(DIM),
+            ),
+            Span::styled(
+                format!("{} ", r.key),
+                Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                truncate(summary, summary_w),
+                if is_selected {
+                    Style::default()
+                        .fg(Color::White)
+                        .add_modifier(Modifier::BOLD)
+                } else {
+                    Style::default().fg(Color::Rgb(180, 180, 200))
+                },
+            ),
+        ];
+        if !r.status.is_empty() {
+            spans.push(Span::styled(format!("  {}", r.status), status_style(&r.status)));
+        }
+        if !r.assignee.is_empty() {
+            spans.push(Span::styled(
+                format!("  {}", r.assignee),
+                Style::default().fg(Color::Rgb(140, 200, 255)),
+            ));
+        }
+        lines.push(Line::from(spans));
+    }
+    (lines, offsets)
+}
+
+/// `♦ EPIC-1 Summary › ◆ STORY-2 Summary › KEY`, summaries shortened to fit.
+fn breadcrumb_line(detail: &IssueDetail, width: usize) -> Line<'static> {
+    let sep = " › ";
+    let keys_w: usize = detail
+        .ancestors
+        .iter()
+        .map(|a| a.key.chars().count() + 3 + sep.len())
+        .sum::<usize>()
+        + detail.key.len();
+    let summary_w = width.saturating_sub(keys_w) / detail.ancestors.len().max(1);
+
+    let mut spans = Vec::new();
+    for a in &detail.ancestors {
+        let (icon, color) = issue_type_icon(&a.issue_type);
+        spans.push(Span::styled(format!("{icon} "), Style::default().fg(color)));
+        spans.push(Span::styled(
+            a.key.clone(),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ));
+        if summary_w > 3 {
+            spans.push(Span::styled(
+                format!(" {}", truncate(&a.summary, summary_w.saturating_sub(1))),
+                Style::default().fg(Color::Rgb(180, 180, 200)),
+            ));
+        }
+        spans.push(Span::styled(sep, Style::default().fg(DIM)));
+    }
+    spans.push(Span::styled(detail.key.clone(), Style::default().fg(DIM)));
+    Line::from(spans)
+}
+
+/// `Team: Platform · Severity: S2` for the configured custom fields.
+fn custom_fields_line(detail: &IssueDetail, width: usize) -> Line<'static> {
+    let mut spans = vec![Span::raw(" ")];
+    let mut used = 1;
+    for (i, (name, value)) in detail.custom.iter().enumerate() {
+        let sep = if i == 0 { "" } else { " · " };
+        let label_w = sep.chars().count() + name.chars().count() + 2;
+        let value = truncate(value, width.saturating_sub(used + label_w));
+        if value.is_empty() {
+            break;
+        }
+        used += label_w + value.chars().count();
+        spans.push(Span::styled(sep, Style::default().fg(DIM)));
+        spans.push(Span::styled(format!("{name}: "), Style::default().fg(DIM)));
+        spans.push(Span::styled(value, Style::default().fg(Color::Rgb(180, 180, 200))));
+    }
+    Line::from(spans)
+}
+
+fn pr_lines(
+    prs: &[GithubPR],
+    selected: Option<usize>,
+    width: usize,
+) -> (Vec<Line<'static>>, Vec<usize>) {
+    // GitHub dark mode palette
+    const GH_BLUE: Color     = Color::Rgb(88, 166, 255);  // #58a6ff accent
+    const GH_TEXT: Color     = Color::Rgb(230, 237, 243); // #e6edf3 fg-default
+    const GH_MUTED: Color    = Color::Rgb(139, 148, 158); // #8b949e fg-muted
+    const GH_GREEN: Color    = Color::Rgb(63, 185, 80);   // #3fb950 open
+    const GH_PURPLE: Color   = Color::Rgb(137, 87, 229);  // #8957e5 merged
+    const GH_RED: Color      = Color::Rgb(248, 81, 73);   // #f85149 closed
+
+    if prs.is_empty() {
+        return (
+            vec![Line::from(Span::styled(
+                "  No pull requests found",
+                Style::default().fg(GH_MUTED),
+            ))],
+            Vec::new(),
+        );
+    }
+
+    let mut lines = Vec::new();
+    let mut offsets = Vec::new();
+    for (i, pr) in prs.iter().enumerate() {
+        let is_selected = selected == Some(i);
+        let marker = if is_selected { "▶ " } else { "  " };
+        let (state_label, state_color) = match pr.state.as_str() {
+            "open"   => ("● OPEN",   GH_GREEN),
+            "closed" => ("✕ CLOSED", GH_RED),
+            _        => ("⎇ MERGED", GH_PURPLE),
+        };
+        let title_fg = if is_selected { GH_TEXT } else { Color::Rgb(200, 207, 216) };
+        // Reserve space for: marker + "#NNNNN " + "  STATE_LABEL" + "  @user"
+        let badge_w = state_label.chars().count() + 2;
+        let user_w = pr.user.len() + 3;
+        let max_title = width.saturating_sub(2 + 7 + badge_w + user_w);
+        offsets.push(lines.len());
+        lines.push(Line::from(vec![
+            Span::styled(marker, Style::default().fg(GH_BLUE)),
+            Span::styled(
+                format!("#{:<5} ", pr.number),
+                Style::default().fg(GH_BLUE).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(truncate(&pr.title, max_title), Style::default().fg(title_fg)),
+            Span::styled(
+                format!("  {state_label}"),
+                Style::default().fg(state_color).add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(format!("  @{}", pr.user), Style::default().fg(GH_MUTED)),
+        ]));
+    }
+    (lines, offsets)
+}
+
+// ── Issue history ────────────────────────────────────────────
+
+/// Time spent in each status so far, plus the cycle time once done.
+fn flow_lines(detail: &IssueDetail, categories: &std::collections::HashMap<String, String>) -> Vec<Line<'static>> {
+    let current = (detail.status.as_str(), detail.status_id.as_str());
+    let spans = flow::status_spans(&detail.created, current, &detail.history, jira::now_timestamp());
+    let totals = flow::time_in_status(&spans);
+    if totals.is_empty() {
+        return Vec::new();
+    }
+    let label = Style::default().fg(Color::Rgb(180, 180, 200));
+    let mut lines = vec![Line::from(Span::styled(
+        "  Time in status",
+        label.add_modifier(Modifier::BOLD),
+    ))];
+    for (status, secs) in totals {
+        lines.push(Line::from(vec![
+            Span::styled(format!("    {:<20}", truncate(&status, 20)), status_style(&status)),
+            Span::styled(flow::format_duration(secs), label),
+        ]));
+    }
+    if let Some(cycle) = flow::cycle_time(&spans, categories) {
+        lines.push(Line::from(vec![
+            Span::styled("  Cycle time  ", label.add_modifier(Modifier::BOLD)),
+            Span::styled(flow::format_duration(cycle), Style::default().fg(Color::Rgb(140, 220, 140))),
+        ]));
+    }
+    lines.push(Line::from(""));
+    lines
+}
+
+fn history_lines(
+    detail: &IssueDetail,
+    width: usize,
+    categories: &std::collections::HashMap<String, String>,
+) -> Vec<Line<'static>> {
+    let from_style = Style::default().fg(Color::Rgb(220, 140, 140));
+    let to_style = Style::default().fg(Color::Rgb(140, 220, 140));
+    if let Some(e) = &detail.history_error {
+        return vec![Line::from(Span::styled(
+            format!("  Error loading history: {e}"),
+            Style::default().fg(Color::Red),
+        ))];
+    }
+    let mut lines: Vec<Line<'static>> = flow_lines(detail, categories);
+
+    if detail.history.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  No history",
+            Style::default().fg(Color::DarkGray),
+        )));
+        return lines;
+    }
+
+    for entry in &detail.history {
+        lines.push(Line::from(vec![
+            Span::styled("  ", Style::default()),
+            Span::styled(
+                entry.author.clone(),
+                Style::default()
+                    .fg(Color::Rgb(140, 200, 255))
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled(
+                format!("  {}", jira::format_timestamp(&entry.created)),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]));
 
-        for (i, t) in app.transitions.iter().enumerate() {
-            let selected = i == app.transition_selected;
-            let is_current = t.to_status == current_status;
-            let marker = if selected { "▶ " } else { "  " };
-            let mut spans = vec![
-                Span::styled(
-                    format!("│ {marker}"),
-                    Style::default().fg(Color::Rgb(100, 100, 140)),
-                ),
+        for item in &entry.items {
+            let multiline = item.from.contains('\n') || item.to.contains('\n');
+            if item.field.eq_ignore_ascii_case("description") || multiline {
+                lines.push(Line::from(Span::styled(
+                    format!("    {}", item.field),
+                    Style::default().fg(Color::Rgb(180, 180, 200)),
+                )));
+                lines.extend(diff_lines(&item.from, &item.to, width.saturating_sub(8)));
+                continue;
+            }
+            let from = if item.from.is_empty() { "(none)" } else { item.from.as_str() };
+            let to = if item.to.is_empty() { "(none)" } else { item.to.as_str() };
+            let to_style = if item.field == "status" {
+                status_style(to)
+            } else {
+                to_style
+            };
+            lines.push(Line::from(vec![
                 Span::styled(
-                    t.name.clone(),
-                    if selected {
-                        Style::default()
-                            .fg(Color::White)
-                            .add_modifier(Modifier::BOLD)
-                    } else {
-                        Style::default().fg(Color::Rgb(180, 180, 200))
-                    },
+                    format!("    {}: ", item.field),
+                    Style::default().fg(Color::Rgb(180, 180, 200)),
                 ),
-            ];
-            if is_current {
-                spans.push(Span::styled(
-                    " (current)",
-                    Style::default().fg(Color::DarkGray),
-                ));
-            }
-            bottom_lines.push(Line::from(spans));
+                Span::styled(from.to_string(), from_style),
+                Span::styled(" → ", Style::default().fg(DIM)),
+                Span::styled(to.to_string(), to_style),
+            ]));
         }
+        lines.push(Line::from(""));
+    }
 
-        bottom_lines.push(Line::from(Span::styled(
-            format!("└{}", "─".repeat(inner_w.saturating_sub(1))),
-            Style::default().fg(Color::Rgb(100, 100, 140)),
-        )));
-        bottom_lines.push(Line::from(Span::styled(
-            "↑↓:Navigate  Enter:Confirm  Esc:Cancel",
-            Style::default().fg(Color::Rgb(100, 100, 120)),
-        )));
-    } else {
-        if !app.detail_status_msg.is_empty() {
-            let elapsed_ms = app.detail_status_set_at.elapsed().as_millis();
-            let is_error = app.detail_status_msg.starts_with("Error");
-            let fg = if is_error {
-                Color::Rgb(220, 140, 140)
+    lines
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum DiffOp {
+    Same,
+    Removed,
+    Added,
+}
+
+/// Line diff of `old` → `new` via longest common subsequence.
+fn line_diff<'a>(old: &'a str, new: &'a str) -> Vec<(DiffOp, &'a str)> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+
+    // Quadratic table; fall back to remove-all/add-all for huge texts
+    if a.len() * b.len() > 1_000_000 {
+        return a
+            .iter()
+            .map(|l| (DiffOp::Removed, *l))
+            .chain(b.iter().map(|l| (DiffOp::Added, *l)))
+            .collect();
+    }
+
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] {
+                lcs[i + 1][j + 1] + 1
             } else {
-                rainbow_color(elapsed_ms, 0.6, 0.7)
+                lcs[i + 1][j].max(lcs[i][j + 1])
             };
-            bottom_lines.push(Line::from(Span::styled(
-                app.detail_status_msg.clone(),
-                Style::default().fg(fg),
-            )));
         }
-        bottom_lines.push(Line::from(Span::styled(
-            "↑↓:Scroll  n/p:Comment  y:Copy  l:Link  c:Add  e:Edit  x:Del  s:Summary  t:Transition  g:PRs  Enter:Browser  Esc:Close",
-            Style::default().fg(Color::Rgb(100, 100, 120)),
-        )));
     }
 
-    f.render_widget(Paragraph::new(bottom_lines), bottom_area);
+    let mut ops = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] == b[j] {
+            ops.push((DiffOp::Same, a[i]));
+            i += 1;
+            j += 1;
+        } else if lcs[i + 1][j] >= lcs[i][j + 1] {
+            ops.push((DiffOp::Removed, a[i]));
+            i += 1;
+        } else {
+            ops.push((DiffOp::Added, b[j]));
+            j += 1;
+        }
+    }
+    ops.extend(a[i..].iter().map(|l| (DiffOp::Removed, *l)));
+    ops.extend(b[j..].iter().map(|l| (DiffOp::Added, *l)));
+    ops
+}
+
+/// Changed lines with one line of context; unchanged stretches collapse to `⋯`.
+fn diff_lines(old: &str, new: &str, width: usize) -> Vec<Line<'static>> {
+    let ops = line_diff(old, new);
+    let near_change = |i: usize| {
+        let lo = i.saturating_sub(1);
+        let hi = (i + 1).min(ops.len() - 1);
+        ops[lo..=hi].iter().any(|(op, _)| *op != DiffOp::Same)
+    };
+
+    let mut lines = Vec::new();
+    let mut skipped = false;
+    for (i, (op, text)) in ops.iter().enumerate() {
+        if *op == DiffOp::Same && !near_change(i) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            lines.push(Line::from(Span::styled("      ⋯", Style::default().fg(DIM))));
+            skipped = false;
+        }
+        let (sign, style) = match op {
+            DiffOp::Same => (' ', Style::default().fg(DIM)),
+            DiffOp::Removed => ('-', Style::default().fg(Color::Rgb(220, 110, 110))),
+            DiffOp::Added => ('+', Style::default().fg(Color::Rgb(110, 200, 110))),
+        };
+        let wrapped = word_wrap(text, width.max(10));
+        for (k, part) in wrapped.into_iter().enumerate() {
+            let lead = if k == 0 { sign } else { ' ' };
+            lines.push(Line::from(Span::styled(format!("    {lead} {part}"), style)));
+        }
+    }
+    if skipped {
+        lines.push(Line::from(Span::styled("      ⋯", Style::default().fg(DIM))));
+    }
+    lines
 }
 
 // ── Markdown-like rendering ─────────────────────────────────
@@ -1608,8 +2934,8 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
         }
 
         // Headings
-        if raw_line.starts_with("### ") {
-            for sub in word_wrap(&raw_line[4..], width) {
+        if let Some(heading) = raw_line.strip_prefix("### ") {
+            for sub in word_wrap(heading, width) {
                 lines.push(Line::from(Span::styled(
                     sub,
                     Style::default()
@@ -1619,8 +2945,8 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
             }
             continue;
         }
-        if raw_line.starts_with("## ") {
-            for sub in word_wrap(&raw_line[3..], width) {
+        if let Some(heading) = raw_line.strip_prefix("## ") {
+            for sub in word_wrap(heading, width) {
                 lines.push(Line::from(Span::styled(
                     sub,
                     Style::default()
@@ -1630,8 +2956,8 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
             }
             continue;
         }
-        if raw_line.starts_with("# ") {
-            for sub in word_wrap(&raw_line[2..], width) {
+        if let Some(heading) = raw_line.strip_prefix("# ") {
+            for sub in word_wrap(heading, width) {
                 lines.push(Line::from(Span::styled(
                     sub,
                     Style::default()
@@ -1643,8 +2969,7 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
         }
 
         // Blockquote — may contain nested lists/headings
-        if raw_line.starts_with("> ") {
-            let inner = &raw_line[2..];
+        if let Some(inner) = raw_line.strip_prefix("> ") {
             let bar = || {
                 Span::styled(
                     "│ ".to_string(),
@@ -1659,7 +2984,7 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
             };
 
             // Nested bullet list inside blockquote
-            if inner.starts_with("  - ") {
+            if let Some(item_text) = inner.strip_prefix("  - ") {
                 emit_prefixed_wrapped(
                     &mut lines,
                     vec![
@@ -1670,7 +2995,7 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
                         bar_cont(),
                         Span::styled("  ".to_string(), Style::default()),
                     ],
-                    &inner[4..],
+                    item_text,
                     width,
                 );
             // Nested numbered list inside blockquote
@@ -1704,7 +3029,7 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
         }
 
         // Bullet list
-        if raw_line.starts_with("  - ") {
+        if let Some(item_text) = raw_line.strip_prefix("  - ") {
             emit_prefixed_wrapped(
                 &mut lines,
                 vec![
@@ -1712,7 +3037,7 @@ fn markdown_to_lines(text: &str, width: usize) -> Vec<Line<'static>> {
                     Span::styled("• ".to_string(), Style::default().fg(ACCENT)),
                 ],
                 vec![Span::styled("    ".to_string(), Style::default())],
-                &raw_line[4..],
+                item_text,
                 width,
             );
             continue;
@@ -2047,6 +3372,196 @@ fn rainbow_color(elapsed_ms: u128, saturation: f64, lightness: f64) -> Color {
 
 // ── Notifications view ───────────────────────────────────────
 
+// ── Reports ──────────────────────────────────────────────────
+
+fn draw_reports_view(f: &mut Frame, app: &App, area: Rect) {
+    let [top, bottom] =
+        Layout::vertical([Constraint::Percentage(55), Constraint::Percentage(45)]).areas(area);
+    draw_burndown(f, app, top);
+    draw_velocity(f, app, bottom);
+}
+
+fn report_block(title: String) -> Block<'static> {
+    Block::default()
+        .borders(Borders::ALL)
+        .border_style(Style::default().fg(Color::Rgb(60, 60, 80)))
+        .title(Span::styled(
+            title,
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ))
+}
+
+fn draw_burndown(f: &mut Frame, app: &App, area: Rect) {
+    let (Some(b), Some(sprint)) = (&app.burndown, &app.sprint) else {
+        let block = report_block(" Burndown ".to_string());
+        let msg = Paragraph::new(Span::styled("  No active sprint", Style::default().fg(DIM)))
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    };
+
+    const DAY: f64 = 86400.0;
+    let span = ((b.end - b.start) as f64 / DAY).max(1.0);
+    let top = b.total.max(1.0);
+    let ideal = [(0.0, b.total), (span, 0.0)];
+
+    // Step down at each completion, then carry the level on to today
+    let mut remaining = b.total;
+    let mut actual = vec![(0.0, remaining)];
+    for (at, value) in &b.completed {
+        let x = (at - b.start) as f64 / DAY;
+        actual.push((x, remaining));
+        remaining -= value;
+        actual.push((x, remaining));
+    }
+    let today = (jira::now_timestamp().min(b.end) - b.start) as f64 / DAY;
+    actual.push((today.max(0.0), remaining));
+
+    let datasets = vec![
+        Dataset::default()
+            .name("Ideal")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(DIM))
+            .data(&ideal),
+        Dataset::default()
+            .name("Remaining")
+            .marker(Marker::Braille)
+            .graph_type(GraphType::Line)
+            .style(Style::default().fg(Color::Rgb(90, 180, 120)))
+            .data(&actual),
+    ];
+
+    let mid = b.start + (b.end - b.start) / 2;
+    let axis_style = Style::default().fg(Color::Rgb(120, 120, 140));
+    let chart = Chart::new(datasets)
+        .block(report_block(format!(
+            " Burndown: {} ({} {} left of {}) ",
+            sprint.name, remaining, b.unit, b.total
+        )))
+        .x_axis(
+            Axis::default()
+                .style(axis_style)
+                .bounds([0.0, span])
+                .labels([jira::format_day(b.start), jira::format_day(mid), jira::format_day(b.end)]),
+        )
+        .y_axis(
+            Axis::default()
+                .style(axis_style)
+                .bounds([0.0, top])
+                .labels(["0".to_string(), format!("{}", top / 2.0), format!("{top}")]),
+        );
+    f.render_widget(chart, area);
+}
+
+fn draw_velocity(f: &mut Frame, app: &App, area: Rect) {
+    let n = app.velocity.len();
+    if n == 0 {
+        let block = report_block(" Velocity ".to_string());
+        let msg = Paragraph::new(Span::styled("  No closed sprints", Style::default().fg(DIM)))
+            .block(block);
+        f.render_widget(msg, area);
+        return;
+    }
+
+    let average = app.velocity.iter().map(|v| v.completed).sum::<f64>() / n as f64;
+    let block = report_block(format!(
+        " Velocity: last {n} sprints, committed / completed, avg {average:.1} "
+    ));
+    let inner_w = block.inner(area).width as usize;
+    // Two bars per sprint plus a gap of 2
+    let bar_w = ((inner_w / n).saturating_sub(2) / 2).clamp(1, 8) as u16;
+
+    let mut chart = BarChart::default()
+        .block(block)
+        .bar_width(bar_w)
+        .bar_gap(0)
+        .group_gap(2);
+    for sprint in &app.velocity {
+        let label = truncate(&sprint.name, (bar_w as usize * 2).max(1));
+        let bars = [
+            Bar::default()
+                .value(sprint.committed.round() as u64)
+                .style(Style::default().fg(Color::Rgb(80, 80, 110))),
+            Bar::default()
+                .value(sprint.completed.round() as u64)
+                .style(Style::default().fg(Color::Rgb(90, 180, 120))),
+        ];
+        chart = chart.data(BarGroup::default().label(Line::from(label)).bars(&bars));
+    }
+    f.render_widget(chart, area);
+}
+
+// ── Flow stats ───────────────────────────────────────────────
+
+fn draw_stats_view(f: &mut Frame, app: &App, area: Rect) {
+    let Some(stats) = &app.flow_stats else {
+        return;
+    };
+    let [summary_area, body] =
+        Layout::vertical([Constraint::Length(5), Constraint::Min(3)]).areas(area);
+    let [status_area, issues_area] =
+        Layout::horizontal([Constraint::Percentage(40), Constraint::Percentage(60)]).areas(body);
+
+    let label = Style::default().fg(Color::Rgb(180, 180, 200));
+    let value = Style::default().fg(Color::White).add_modifier(Modifier::BOLD);
+    let fmt = |d: Option<i64>| d.map_or("–".to_string(), flow::format_duration);
+    let summary = vec![
+        Line::from(vec![
+            Span::styled("  Finished: ", label),
+            Span::styled(stats.issues.len().to_string(), value),
+            Span::styled("   Throughput: ", label),
+            Span::styled(format!("{:.1}/week", stats.throughput_per_week), value),
+        ]),
+        Line::from(vec![
+            Span::styled("  Cycle time median: ", label),
+            Span::styled(fmt(stats.median), value),
+            Span::styled("   p85: ", label),
+            Span::styled(fmt(stats.p85), value),
+        ]),
+        Line::from(Span::styled(
+            "  Cycle time runs from first In Progress to Done",
+            Style::default().fg(DIM),
+        )),
+    ];
+    f.render_widget(
+        Paragraph::new(summary).block(report_block(format!(" Flow: last {} days ", stats.days))),
+        summary_area,
+    );
+
+    let status_block = report_block(" Average time in status ".to_string());
+    let bar_w = status_block.inner(status_area).width.saturating_sub(32) as usize;
+    let longest = stats.avg_in_status.first().map_or(1, |(_, s)| (*s).max(1));
+    let status_lines: Vec<Line> = stats
+        .avg_in_status
+        .iter()
+        .map(|(status, secs)| {
+            let filled = (*secs as usize * bar_w) / longest as usize;
+            Line::from(vec![
+                Span::styled(format!("  {:<18}", truncate(status, 18)), status_style(status)),
+                Span::styled(format!("{:>9}  ", flow::format_duration(*secs)), label),
+                Span::styled("█".repeat(filled), Style::default().fg(Color::Rgb(90, 120, 200))),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(status_lines).block(status_block), status_area);
+
+    let issues_block = report_block(" Cycle time per issue ".to_string());
+    let summary_w = issues_block.inner(issues_area).width.saturating_sub(26) as usize;
+    let issue_lines: Vec<Line> = stats
+        .issues
+        .iter()
+        .map(|(key, summary, cycle)| {
+            Line::from(vec![
+                Span::styled(format!("  {:<12}", key), value),
+                Span::styled(format!("{:>9}  ", fmt(*cycle)), label),
+                Span::styled(truncate(summary, summary_w), Style::default().fg(Color::Rgb(200, 200, 210))),
+            ])
+        })
+        .collect();
+    f.render_widget(Paragraph::new(issue_lines).block(issues_block), issues_area);
+}
+
 fn draw_notifications_view(f: &mut Frame, app: &App, area: Rect) {
     const RESOLUTION_W: u16 = 14;
     const BORDERS: u16 = 2;
@@ -2186,6 +3701,20 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
     };
 
     let (mode_text, help_text) = match app.mode {
+        Mode::Normal if app.visual_anchor.is_some() || !app.marked.is_empty() => (
+            Span::styled(
+                if app.visual_anchor.is_some() {
+                    format!(" VISUAL {} ", app.marked.len())
+                } else {
+                    format!(" {} MARKED ", app.marked.len())
+                },
+                Style::default()
+                    .bg(MARKED_BG)
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " Space:Mark  V:Range  m:Mute  h:Highlight  t:Transition  a:Assign  L:Label  y:Copy keys  E:Export  Esc:Clear marks ".to_string(),
+        ),
         Mode::Normal => (
             Span::styled(
                 " NORMAL ",
@@ -2193,10 +3722,12 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                     .bg(Color::Rgb(60, 60, 120))
                     .fg(Color::White),
             ),
-            {
+            if app.board_view {
+                " q:Quit  b:Table  ←→:Column  j/k:Card  </>/Shift+←→:Move card  Enter:Open  w:Browser  n:Notes  h:Highlight  m:Mute  Space:Mark  t:Transition  a:Assign  /:Search  r:Refresh  ?:Legend ".to_string()
+            } else {
                 let spacing_label = if app.config.comfortable_spacing { "z:Spacing[ON]" } else { "z:Spacing[OFF]" };
                 format!(
-                    " q:Quit  j/k:Nav  Enter:Open  w:Browser  s:Status  n:Notes  h:Highlight  m:Mute  o:Sort  c:Columns  y:Copy  f:Filter  /:Search  {tree_label}  {spacing_label}  r:Refresh  N:Notifications  ?:Legend "
+                    " q:Quit  j/k:Nav  Enter:Open  w:Browser  s:Status  n:Notes  h:Highlight  m:Mute  P:Pin  Space/V:Mark  t:Transition  a:Assign  L:Label  E:Export  b:Board  S:Sprint only  M:Move to sprint  R:Reports  F:Flow stats  o:Sort  J/K:Reorder  G:Group  c:Columns  y:Copy  f:Filter  /:Search  g:Go to  {tree_label}  ←→:Fold  -/+:Fold all  {spacing_label}  r:Refresh  N:Notifications  ?:Legend "
                 )
             },
         ),
@@ -2208,7 +3739,7 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                     .fg(Color::Black)
                     .add_modifier(Modifier::BOLD),
             ),
-            " Type to filter  ↑↓:Navigate  Enter:Keep filter  Esc:Clear ".to_string(),
+            " Type to filter, field:value to narrow, -term to exclude  Tab:Complete  ^R:Ranking  ^N:Notes  ^D:Search Jira text  ↑↓:Navigate  Enter:Keep filter  Esc:Clear ".to_string(),
         ),
         Mode::ConfirmBrowser => (
             Span::styled(
@@ -2258,8 +3789,14 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                     .fg(Color::White)
                     .add_modifier(Modifier::BOLD),
             ),
-            " ↑↓:Scroll  n/N:Select comment  c:Add  e:Edit  x:Del comment  Enter:Browser  Esc:Close "
-                .to_string(),
+            format!(
+                " Tab/1-6:Switch tab  ↑↓:Scroll/Select  {}  /:Find  c:Add  e:Edit  x:Del comment  o:Related  u:Parent  Bksp/[:Back  ]:Forward  Enter:Browser  Esc:Close ",
+                if app.detail_search_input.is_empty() {
+                    "n/p:Select comment"
+                } else {
+                    "n/p:Next/prev match  Esc:Clear find"
+                }
+            ),
         ),
         Mode::DetailAddingComment => (
             Span::styled(
@@ -2301,7 +3838,37 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
             ),
             " ↑↓:Navigate  Enter:Confirm  Esc:Cancel ".to_string(),
         ),
-        Mode::DetailConfirmTransition => (
+        Mode::AssignPicker => (
+            Span::styled(
+                " ASSIGN ",
+                Style::default()
+                    .bg(Color::Rgb(80, 160, 80))
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " Type a name  ↑↓:Navigate  Enter:Assign  Esc:Cancel ".to_string(),
+        ),
+        Mode::AddingLabel => (
+            Span::styled(
+                " LABEL ",
+                Style::default()
+                    .bg(Color::Rgb(80, 160, 80))
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " Enter:Add  Esc:Cancel ".to_string(),
+        ),
+        Mode::TransitionPicker => (
+            Span::styled(
+                " TRANSITION ",
+                Style::default()
+                    .bg(Color::Rgb(80, 160, 80))
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " ↑↓:Navigate  Enter:Confirm  Esc:Cancel ".to_string(),
+        ),
+        Mode::DetailConfirmTransition | Mode::ConfirmTransition => (
             Span::styled(
                 " CONFIRM TRANSITION ",
                 Style::default()
@@ -2309,7 +3876,7 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                     .fg(Color::Black)
                     .add_modifier(Modifier::BOLD),
             ),
-            " y/Enter:Confirm  n/Esc:Cancel ".to_string(),
+            " Tab:Next field  ←→:Change option  Enter:Confirm  Esc:Cancel ".to_string(),
         ),
         Mode::DetailEditingSummary => (
             Span::styled(
@@ -2321,6 +3888,46 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
             ),
             " Enter:Save  Esc:Cancel ".to_string(),
         ),
+        Mode::DetailWatchers => (
+            Span::styled(
+                " WATCHERS ",
+                Style::default()
+                    .bg(Color::Rgb(80, 120, 180))
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " ↑↓:Navigate  a:Add  x:Remove  w:Watch/Unwatch  Esc:Close ".to_string(),
+        ),
+        Mode::DetailAddingWatcher => (
+            Span::styled(
+                " ADD WATCHER ",
+                Style::default()
+                    .bg(Color::Green)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " Type a name  ↑↓:Navigate  Enter:Add  Esc:Cancel ".to_string(),
+        ),
+        Mode::DetailSearching => (
+            Span::styled(
+                " FIND ",
+                Style::default()
+                    .bg(Color::Rgb(255, 200, 60))
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " Type to find in this tab  Enter:Keep (n/p:Next/prev)  Esc:Clear ".to_string(),
+        ),
+        Mode::DetailRelated => (
+            Span::styled(
+                " RELATED ",
+                Style::default()
+                    .bg(Color::Rgb(80, 120, 180))
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " ↑↓:Navigate  Enter:Open here  Esc:Cancel ".to_string(),
+        ),
         Mode::HighlightPicker => (
             Span::styled(
                 " HIGHLIGHT ",
@@ -2339,6 +3946,46 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                     .fg(Color::Black)
                     .add_modifier(Modifier::BOLD),
             ),
+            " ↑↓:Navigate  Enter:Sort by  Space:Add/remove key  r:Reverse  Esc:Close ".to_string(),
+        ),
+        Mode::Stats => (
+            Span::styled(
+                " STATS ",
+                Style::default()
+                    .bg(Color::Rgb(40, 70, 60))
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " ←→:Period  r:Reload  Esc:Close ".to_string(),
+        ),
+        Mode::Reports => (
+            Span::styled(
+                " REPORTS ",
+                Style::default()
+                    .bg(Color::Rgb(40, 70, 60))
+                    .fg(Color::White)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " r:Reload  Esc:Close ".to_string(),
+        ),
+        Mode::SprintPicker => (
+            Span::styled(
+                " SPRINT ",
+                Style::default()
+                    .bg(Color::Rgb(100, 160, 200))
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            " ↑↓:Navigate  Enter:Move  Esc:Cancel ".to_string(),
+        ),
+        Mode::GroupPicker => (
+            Span::styled(
+                " GROUP BY ",
+                Style::default()
+                    .bg(Color::Rgb(100, 160, 200))
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD),
+            ),
             " ↑↓:Navigate  Enter:Select  Esc:Cancel ".to_string(),
         ),
         Mode::ColumnPicker => (
@@ -2349,17 +3996,17 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
                     .fg(Color::Black)
                     .add_modifier(Modifier::BOLD),
             ),
-            " ↑↓:Navigate  Space:Toggle  Esc:Close ".to_string(),
+            " ↑↓:Navigate  Space:Toggle  J/K:Move  +/-:Width  Esc:Close ".to_string(),
         ),
-        Mode::DetailPRList => (
+        Mode::QuickOpen => (
             Span::styled(
-                " PULL REQUESTS ",
+                " GO TO ",
                 Style::default()
-                    .bg(Color::Rgb(60, 120, 80))
-                    .fg(Color::White)
+                    .bg(Color::Rgb(100, 160, 200))
+                    .fg(Color::Black)
                     .add_modifier(Modifier::BOLD),
             ),
-            " ↑↓:Navigate  Enter:Open in browser  Esc:Close ".to_string(),
+            " Type a key or paste a Jira URL  ↑↓:Recent  Enter:Open  Esc:Cancel ".to_string(),
         ),
         Mode::ConfirmQuit => (
             Span::styled(
@@ -2411,12 +4058,51 @@ fn draw_status_bar(f: &mut Frame, app: &App, area: Rect) {
     spans.extend(status_spans);
     let line = Line::from(spans);
 
-    f.render_widget(Paragraph::new(line), area);
+    let sprint = sprint_summary(app).filter(|_| app.mode == Mode::Normal);
+    let Some(sprint) = sprint else {
+        f.render_widget(Paragraph::new(line), area);
+        return;
+    };
+    let sprint_w = sprint.width() as u16;
+    let [left, right] =
+        Layout::horizontal([Constraint::Min(0), Constraint::Length(sprint_w)]).areas(area);
+    f.render_widget(Paragraph::new(line), left);
+    f.render_widget(Paragraph::new(sprint), right);
+}
+
+/// "Sprint 42 · 3d left · 12/20 done" for the right end of the status bar.
+fn sprint_summary(app: &App) -> Option<Line<'static>> {
+    let sprint = app.sprint.as_ref()?;
+    let total = app.sprint_keys.len();
+    let mut spans = vec![Span::styled(
+        format!(" {} ", sprint.name),
+        Style::default()
+            .bg(Color::Rgb(40, 70, 60))
+            .fg(Color::White)
+            .add_modifier(Modifier::BOLD),
+    )];
+    if let Some(days) = app.sprint_days_left() {
+        spans.push(Span::styled(format!(" {days}d left "), Style::default().fg(ACCENT)));
+    }
+    let filled = (app.sprint_done * 10).checked_div(total).unwrap_or(0);
+    spans.push(Span::styled(
+        "█".repeat(filled),
+        Style::default().fg(Color::Rgb(90, 180, 120)),
+    ));
+    spans.push(Span::styled(
+        "░".repeat(10 - filled),
+        Style::default().fg(Color::Rgb(60, 60, 80)),
+    ));
+    spans.push(Span::styled(
+        format!(" {}/{total} done{} ", app.sprint_done, if app.sprint_only { " · filtered" } else { "" }),
+        Style::default().fg(Color::Rgb(180, 180, 200)),
+    ));
+    Some(Line::from(spans))
 }
 
 fn draw_search_bar(f: &mut Frame, app: &App, area: Rect) {
     let cursor = if app.mode == Mode::Searching { "│" } else { "" };
-    let line = Line::from(vec![
+    let mut line = Line::from(vec![
         Span::styled(
             " /",
             Style::default()
@@ -2429,10 +4115,36 @@ fn draw_search_bar(f: &mut Frame, app: &App, area: Rect) {
         ),
         Span::styled(cursor.to_string(), Style::default().fg(Color::Rgb(255, 200, 60))),
         Span::styled(
-            format!("  ({} matches)", app.rows.len()),
+            format!(
+                "  ({} matches, {}{})",
+                app.rows.len(),
+                if app.config.search_natural_order { "table order" } else { "best first" },
+                if app.config.search_notes { ", incl. notes" } else { "" }
+            ),
             Style::default().fg(Color::Rgb(100, 100, 120)),
         ),
     ]);
+    if let Some(count) = app.deep_search_count() {
+        line.spans.push(Span::styled(
+            format!("  ⌕ {count} from Jira text search"),
+            Style::default().fg(Color::Rgb(140, 200, 255)),
+        ));
+    }
+    if app.mode == Mode::Searching {
+        let completions = app.search_completions();
+        if !completions.is_empty() {
+            line.spans.push(Span::styled("  Tab: ", Style::default().fg(DIM)));
+            line.spans.push(Span::styled(
+                completions.iter().take(8).cloned().collect::<Vec<_>>().join("  "),
+                Style::default().fg(Color::Rgb(140, 200, 255)),
+            ));
+        } else if app.search_input.is_empty() {
+            line.spans.push(Span::styled(
+                "  status:  type:  prio:>=high  assignee:  label:  note:  hl:  muted:no  -term",
+                Style::default().fg(DIM),
+            ));
+        }
+    }
 
     f.render_widget(Paragraph::new(line).style(Style::default().bg(Color::Rgb(25, 25, 35))), area);
 }